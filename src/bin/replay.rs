@@ -0,0 +1,99 @@
+use card_game_simulator::game::Game;
+use card_game_simulator::replay::RecordedPlay;
+use card_game_simulator::save::load_replay;
+
+const USAGE: &str = "Usage: replay <file>
+
+Plays back a game recorded with `card-game-simulator --record <file>`,
+printing the state after every turn and stopping where the game no longer
+matches the recording.";
+
+fn print_game(game: &Game) {
+    game.events
+        .inner
+        .iter()
+        .for_each(|event| println!("{}", event.description()));
+    game.players
+        .inner
+        .iter()
+        .enumerate()
+        .for_each(|(idx, player)| println!("Player #{} Status: {}", idx + 1, player.description()));
+    game.enemies
+        .inner
+        .iter()
+        .enumerate()
+        .for_each(|(idx, enemy)| println!("Enemy #{} Status: {}", idx + 1, enemy.description()));
+}
+
+fn card_names(game: &Game, plays: &[RecordedPlay]) -> String {
+    plays
+        .iter()
+        .map(|play| {
+            let name = game
+                .player()
+                .hand
+                .inner
+                .iter()
+                .find(|card| card.id == play.card)
+                .map(|card| card.name.clone())
+                .unwrap_or_else(|| play.card.clone());
+            let name = match play.target {
+                Some(target) => format!("{} at enemy #{}", name, target + 1),
+                None => name,
+            };
+            match play.ally {
+                Some(ally) => format!("{} for player #{}", name, ally + 1),
+                None => name,
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().collect();
+    let path = match args.get(1) {
+        Some(path) if path != "--help" => path,
+        _ => {
+            println!("{}", USAGE);
+            return;
+        }
+    };
+    let replay = match load_replay(path) {
+        Ok(replay) => replay,
+        Err(err) => {
+            println!("Could not load replay: {}", err);
+            std::process::exit(1);
+        }
+    };
+
+    println!(
+        "Replaying {} turns (seed {})",
+        replay.turns.inner.len(),
+        replay.seed
+    );
+    let playback = replay.play_back();
+    print_game(&playback.games[0]);
+    playback
+        .games
+        .windows(2)
+        .zip(replay.turns.inner.iter())
+        .for_each(|(games, turn)| {
+            println!("-----------------------------------");
+            println!(
+                "Turn {}: player #{} played [{}]",
+                games[0].turn_number,
+                games[0].current_player + 1,
+                card_names(&games[0], &turn.plays)
+            );
+            print_game(&games[1]);
+        });
+    match playback.divergence {
+        Some(divergence) => {
+            println!("-----------------------------------");
+            println!("DIVERGED: {}", divergence.description());
+            std::process::exit(1);
+        }
+        None => println!("Replay matches the recording"),
+    }
+}
@@ -0,0 +1,193 @@
+use card_game_simulator::catalog::Catalog;
+use card_game_simulator::fp_vec::FpVec;
+use card_game_simulator::game::Game;
+use card_game_simulator::player::Player;
+use card_game_simulator::search::{SearchConfig, SearchStrategy};
+use card_game_simulator::simulation::{simulate, SimulationConfig};
+use card_game_simulator::strategy::{
+    ConservativeHealStrategy, GreedyDamageStrategy, PlayAllStrategy, PlayerStrategy, RandomStrategy,
+};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::time::Duration;
+
+const USAGE: &str = "Usage: simulate --enemy <key,key,...> --deck <key,key,...> [options]
+
+Options:
+  --catalog <file>      card/enemy catalog (default: built-in)
+  --strategy <name>     random | play_all | greedy | conservative | search
+                        (default: random)
+  --depth <n>           turns the search strategy looks ahead (default: 2)
+  --nodes <n>           game states the search may evaluate per turn
+                        (default: 50000)
+  --time-ms <n>         time the search may spend per turn (default: no limit)
+  --samples <n>         guesses at the hidden cards the search averages over
+                        (default: 4)
+  --games <n>           number of games (default: 1000)
+  --seed <n>            random seed (default: 0)
+  --max-turns <n>       stop undecided games after n turns (default: 100)
+  --players <n>         players, each with their own copy of the deck
+                        (default: 1)
+  --hp <n>              player hit points (default: 20)
+  --hand <n>            starting hand size (default: whole deck)
+  --draw <n>            cards drawn per turn (default: 0)
+  --power <n>           power per turn (default: 0)";
+
+struct Options {
+    catalog: Catalog,
+    enemies: Vec<String>,
+    deck: Vec<String>,
+    strategy: String,
+    search: SearchConfig,
+    config: SimulationConfig,
+    players: usize,
+    hit_points: i32,
+    hand: Option<usize>,
+    draw: u32,
+    power: i32,
+}
+
+fn arg<'a>(args: &'a [String], flag: &str) -> Option<&'a str> {
+    args.iter()
+        .position(|arg| arg == flag)
+        .and_then(|idx| args.get(idx + 1))
+        .map(|value| value.as_str())
+}
+
+fn parsed<T: std::str::FromStr>(args: &[String], flag: &str, default: T) -> Result<T, String> {
+    match arg(args, flag) {
+        Some(value) => value
+            .parse()
+            .map_err(|_| format!("invalid value for {}: {}", flag, value)),
+        None => Ok(default),
+    }
+}
+
+fn parse_options(args: &[String]) -> Result<Options, String> {
+    let catalog = match arg(args, "--catalog") {
+        Some(path) => Catalog::load(path).map_err(|err| err.to_string())?,
+        None => Catalog::builtin(),
+    };
+    let enemy = arg(args, "--enemy").ok_or("--enemy is required")?;
+    let deck = arg(args, "--deck").ok_or("--deck is required")?;
+    let defaults = SimulationConfig::default();
+    let search_defaults = SearchConfig::default();
+    Ok(Options {
+        enemies: enemy.split(',').map(|key| key.trim().to_string()).collect(),
+        deck: deck.split(',').map(|key| key.trim().to_string()).collect(),
+        strategy: arg(args, "--strategy").unwrap_or("random").to_string(),
+        search: SearchConfig {
+            depth: parsed(args, "--depth", search_defaults.depth)?,
+            node_budget: parsed(args, "--nodes", search_defaults.node_budget)?,
+            samples: parsed(args, "--samples", search_defaults.samples)?,
+            time_limit: arg(args, "--time-ms")
+                .map(|value| {
+                    value
+                        .parse()
+                        .map(Duration::from_millis)
+                        .map_err(|_| format!("invalid value for --time-ms: {}", value))
+                })
+                .transpose()?,
+        },
+        config: SimulationConfig {
+            games: parsed(args, "--games", defaults.games)?,
+            seed: parsed(args, "--seed", defaults.seed)?,
+            max_turns: parsed(args, "--max-turns", defaults.max_turns)?,
+        },
+        players: parsed(args, "--players", 1)?,
+        hit_points: parsed(args, "--hp", 20)?,
+        hand: arg(args, "--hand")
+            .map(|value| {
+                value
+                    .parse()
+                    .map_err(|_| format!("invalid value for --hand: {}", value))
+            })
+            .transpose()?,
+        draw: parsed(args, "--draw", 0)?,
+        power: parsed(args, "--power", 0)?,
+        catalog,
+    })
+}
+
+type StrategyFactory = Box<dyn Fn(u64) -> Box<dyn PlayerStrategy>>;
+
+fn strategy_factory(name: &str, search: &SearchConfig) -> Result<StrategyFactory, String> {
+    match name {
+        "random" => Ok(Box::new(|seed| Box::new(RandomStrategy::new(seed)))),
+        "play_all" => Ok(Box::new(|_| Box::new(PlayAllStrategy))),
+        "greedy" => Ok(Box::new(|_| Box::new(GreedyDamageStrategy))),
+        "conservative" => Ok(Box::new(|_| Box::new(ConservativeHealStrategy::new()))),
+        "search" => {
+            let search = search.clone();
+            Ok(Box::new(move |seed| {
+                Box::new(SearchStrategy::new(search.clone(), seed))
+            }))
+        }
+        other => Err(format!("unknown strategy: {}", other)),
+    }
+}
+
+fn run(options: Options) -> Result<(), String> {
+    let deck_keys: Vec<&str> = options.deck.iter().map(|key| key.as_str()).collect();
+    // Look everything up once so bad keys are reported before any game runs.
+    let enemy_keys: Vec<&str> = options.enemies.iter().map(|key| key.as_str()).collect();
+    let enemies = options
+        .catalog
+        .encounter(&enemy_keys)
+        .map_err(|err| err.to_string())?;
+    let deck = options
+        .catalog
+        .cards(&deck_keys)
+        .map_err(|err| err.to_string())?;
+    let strategy = strategy_factory(&options.strategy, &options.search)?;
+
+    let setup = |rng: &mut StdRng| {
+        let players = (0..options.players.max(1))
+            .map(|_| {
+                let mut cards = deck.inner.clone();
+                cards.shuffle(rng);
+                let player = Player::new(options.hit_points, FpVec::from_vec(cards))
+                    .draws_per_turn(options.draw)
+                    .power_per_turn(options.power);
+                match options.hand {
+                    Some(size) => player.starting_hand(size),
+                    None => player,
+                }
+            })
+            .collect();
+        Game::start_coop(enemies.clone(), FpVec::from_vec(players), rng.gen())
+    };
+
+    let report = simulate(&options.config, setup, strategy);
+    println!(
+        "{}{} vs {} using {} strategy (seed {})",
+        options.deck.join(","),
+        match options.players {
+            0 | 1 => String::new(),
+            players => format!(" x{}", players),
+        },
+        enemies
+            .inner
+            .iter()
+            .map(|enemy| enemy.name.clone())
+            .collect::<Vec<String>>()
+            .join(" + "),
+        options.strategy,
+        options.config.seed
+    );
+    println!("{}", report.description());
+    Ok(())
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().collect();
+    if args.iter().any(|arg| arg == "--help") {
+        println!("{}", USAGE);
+        return;
+    }
+    if let Err(err) = parse_options(&args).and_then(run) {
+        println!("{}\n\n{}", err, USAGE);
+        std::process::exit(1);
+    }
+}
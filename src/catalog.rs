@@ -0,0 +1,921 @@
+use crate::enemy::{Enemy, EnemyMove, MovePattern, PlayerTargeting};
+use crate::fp_vec::FpVec;
+use crate::game_effects::{EnemyEffects, GameEffect};
+use crate::phase::{Phase, PhaseHook};
+use crate::player::PlayerCard;
+use crate::status::{Status, StatusKind, Tick};
+use crate::{
+    CardZone, Damage, DamageAdjustment, DefenseProps, EffectCondition, EffectTarget, EffectTrigger,
+    EffectType, ElementType, Enchantment, PowerCostAdjust,
+};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+
+const DEFAULT_CATALOG: &str = include_str!("../data/default_catalog.toml");
+
+#[derive(Debug)]
+pub enum CatalogError {
+    Io(String, std::io::Error),
+    Parse(toml::de::Error),
+    DuplicateKey(&'static str, String),
+    InvalidEntry {
+        kind: &'static str,
+        key: String,
+        reason: String,
+    },
+    NotFound(&'static str, String),
+}
+
+impl fmt::Display for CatalogError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CatalogError::Io(path, err) => write!(f, "cannot read catalog {}: {}", path, err),
+            CatalogError::Parse(err) => write!(f, "malformed catalog: {}", err),
+            CatalogError::DuplicateKey(kind, key) => {
+                write!(f, "{} '{}' is defined more than once", kind, key)
+            }
+            CatalogError::InvalidEntry { kind, key, reason } => {
+                write!(f, "{} '{}' is invalid: {}", kind, key, reason)
+            }
+            CatalogError::NotFound(kind, key) => {
+                write!(f, "no {} named '{}' in catalog", kind, key)
+            }
+        }
+    }
+}
+
+impl std::error::Error for CatalogError {}
+
+impl From<toml::de::Error> for CatalogError {
+    fn from(err: toml::de::Error) -> Self {
+        CatalogError::Parse(err)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Catalog {
+    cards: Vec<(String, PlayerCard)>,
+    enemies: Vec<(String, Enemy)>,
+}
+
+impl Catalog {
+    pub fn builtin() -> Self {
+        Self::from_toml_str(DEFAULT_CATALOG).expect("bundled default catalog is invalid")
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, CatalogError> {
+        let text = std::fs::read_to_string(path.as_ref())
+            .map_err(|err| CatalogError::Io(path.as_ref().display().to_string(), err))?;
+        Self::from_toml_str(&text)
+    }
+
+    pub fn from_toml_str(text: &str) -> Result<Self, CatalogError> {
+        let file: CatalogFile = toml::from_str(text)?;
+
+        let cards = file.cards.into_iter().try_fold(
+            Vec::new(),
+            |mut cards: Vec<(String, PlayerCard)>, def| {
+                if cards.iter().any(|(key, _)| *key == def.key) {
+                    return Err(CatalogError::DuplicateKey("card", def.key));
+                }
+                let card = def.build()?;
+                cards.push((def.key, card));
+                Ok(cards)
+            },
+        )?;
+
+        let enemies = file.enemies.into_iter().try_fold(
+            Vec::new(),
+            |mut enemies: Vec<(String, Enemy)>, def| {
+                if enemies.iter().any(|(key, _)| *key == def.key) {
+                    return Err(CatalogError::DuplicateKey("enemy", def.key));
+                }
+                let enemy = def.build(&enemies)?;
+                enemies.push((def.key, enemy));
+                Ok(enemies)
+            },
+        )?;
+
+        Ok(Self { cards, enemies })
+    }
+
+    /// Creates a fresh copy of the card stored under `key`, with its own card id.
+    pub fn card(&self, key: &str) -> Result<PlayerCard, CatalogError> {
+        self.cards
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, card)| PlayerCard {
+                id: PlayerCard::new_id(),
+                ..card.clone()
+            })
+            .ok_or_else(|| CatalogError::NotFound("card", key.to_string()))
+    }
+
+    pub fn cards(&self, keys: &[&str]) -> Result<FpVec<PlayerCard>, CatalogError> {
+        keys.iter()
+            .try_fold(FpVec::new(), |cards, key| Ok(cards.push(self.card(key)?)))
+    }
+
+    pub fn enemy(&self, key: &str) -> Result<Enemy, CatalogError> {
+        self.enemies
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, enemy)| enemy.clone())
+            .ok_or_else(|| CatalogError::NotFound("enemy", key.to_string()))
+    }
+
+    /// The enemies for `keys`, in order, for a game against several at once.
+    pub fn encounter(&self, keys: &[&str]) -> Result<FpVec<Enemy>, CatalogError> {
+        keys.iter().try_fold(FpVec::new(), |enemies, key| {
+            Ok(enemies.push(self.enemy(key)?))
+        })
+    }
+
+    pub fn card_keys(&self) -> Vec<&str> {
+        self.cards.iter().map(|(key, _)| key.as_str()).collect()
+    }
+
+    pub fn enemy_keys(&self) -> Vec<&str> {
+        self.enemies.iter().map(|(key, _)| key.as_str()).collect()
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CatalogFile {
+    #[serde(default, rename = "card")]
+    cards: Vec<CardDef>,
+    #[serde(default, rename = "enemy")]
+    enemies: Vec<EnemyDef>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CardDef {
+    key: String,
+    name: String,
+    #[serde(default)]
+    description: String,
+    element: ElementDef,
+    #[serde(default)]
+    cost: i32,
+    #[serde(default = "default_true")]
+    can_play: bool,
+    #[serde(default)]
+    game_start_effects: Vec<EffectDef>,
+    #[serde(default)]
+    start_turn_effects: Vec<EffectDef>,
+    #[serde(default)]
+    play_card_effects: Vec<EffectDef>,
+    #[serde(default)]
+    phase_effects: BTreeMap<PhaseDef, Vec<EffectDef>>,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl CardDef {
+    fn build(&self) -> Result<PlayerCard, CatalogError> {
+        let invalid = |reason: String| CatalogError::InvalidEntry {
+            kind: "card",
+            key: self.key.clone(),
+            reason,
+        };
+        if self.name.trim().is_empty() {
+            return Err(invalid("name must not be empty".to_string()));
+        }
+        if self.cost < 0 {
+            return Err(invalid(format!(
+                "cost must not be negative, got {}",
+                self.cost
+            )));
+        }
+        let card = PlayerCard::new(&self.name, &self.description, self.element.build());
+        Ok(PlayerCard {
+            cost: self.cost,
+            can_play: self.can_play,
+            game_start_effects: build_effects(
+                "game_start_effects",
+                &self.game_start_effects,
+                false,
+            )
+            .map_err(invalid)?,
+            start_turn_effects: build_effects(
+                "start_turn_effects",
+                &self.start_turn_effects,
+                false,
+            )
+            .map_err(invalid)?,
+            play_card_effects: build_effects("play_card_effects", &self.play_card_effects, true)
+                .map_err(invalid)?,
+            phase_effects: build_phase_effects(&self.phase_effects).map_err(invalid)?,
+            ..card
+        })
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct EnemyDef {
+    key: String,
+    name: String,
+    hit_points: i32,
+    #[serde(default)]
+    defense: DefenseDef,
+    /// Shorthand for the standard enemy attack, placed first in `end_turn_effects`.
+    #[serde(default)]
+    turn_damage: Option<i32>,
+    /// The element of the standard attack, if it has one.
+    #[serde(default)]
+    turn_element: Option<ElementDef>,
+    #[serde(default)]
+    start_turn_effects: Vec<EffectDef>,
+    #[serde(default)]
+    end_turn_effects: Vec<EffectDef>,
+    #[serde(default)]
+    player_start_turn_effects: Vec<EffectDef>,
+    #[serde(default)]
+    player_end_turn_effects: Vec<EffectDef>,
+    #[serde(default)]
+    player_play_card_effects: Vec<EffectDef>,
+    #[serde(default)]
+    phase_effects: BTreeMap<PhaseDef, Vec<EffectDef>>,
+    #[serde(default)]
+    enchantments: Vec<EffectDef>,
+    /// Which player the enemy attacks when there are several.
+    #[serde(default)]
+    targeting: TargetingDef,
+    #[serde(default, rename = "move")]
+    moves: Vec<MoveDef>,
+    #[serde(default)]
+    pattern: PatternDef,
+}
+
+impl EnemyDef {
+    // Summons must be defined earlier, which also rules out summoning loops.
+    fn build(&self, defined: &[(String, Enemy)]) -> Result<Enemy, CatalogError> {
+        let invalid = |reason: String| CatalogError::InvalidEntry {
+            kind: "enemy",
+            key: self.key.clone(),
+            reason,
+        };
+        if self.name.trim().is_empty() {
+            return Err(invalid("name must not be empty".to_string()));
+        }
+        if self.hit_points <= 0 {
+            return Err(invalid(format!(
+                "hit_points must be positive, got {}",
+                self.hit_points
+            )));
+        }
+        let enemy = Enemy::new(&self.name, self.hit_points, self.defense.build(), 0);
+        let attack = self
+            .turn_damage
+            .map(|amount| match &self.turn_element {
+                Some(element) => EnemyEffects::elemental_attack(element.build(), amount),
+                None => EnemyEffects::attack(amount),
+            })
+            .map(|attack| FpVec::from_vec(vec![attack]))
+            .unwrap_or_default();
+        Ok(Enemy {
+            start_turn_effects: build_effects(
+                "start_turn_effects",
+                &self.start_turn_effects,
+                false,
+            )
+            .map_err(invalid)?,
+            end_turn_effects: attack.extend(
+                build_effects("end_turn_effects", &self.end_turn_effects, false)
+                    .map_err(invalid)?,
+            ),
+            player_start_turn_effects: build_effects(
+                "player_start_turn_effects",
+                &self.player_start_turn_effects,
+                false,
+            )
+            .map_err(invalid)?,
+            player_end_turn_effects: build_effects(
+                "player_end_turn_effects",
+                &self.player_end_turn_effects,
+                false,
+            )
+            .map_err(invalid)?,
+            player_play_card_effects: build_effects(
+                "player_play_card_effects",
+                &self.player_play_card_effects,
+                false,
+            )
+            .map_err(invalid)?,
+            phase_effects: build_phase_effects(&self.phase_effects).map_err(invalid)?,
+            enchantments: build_effects("enchantments", &self.enchantments, false)
+                .map_err(invalid)?,
+            targeting: self.targeting.build(),
+            moves: self
+                .moves
+                .iter()
+                .enumerate()
+                .try_fold(FpVec::new(), |moves, (idx, def)| {
+                    def.build(defined)
+                        .map(|mv| moves.push(mv))
+                        .map_err(|reason| format!("move[{}] ('{}'): {}", idx, def.name, reason))
+                })
+                .map_err(invalid)?,
+            pattern: self.pattern.build(),
+            ..enemy
+        })
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct MoveDef {
+    name: String,
+    #[serde(default)]
+    effects: Vec<EffectDef>,
+    /// Keys of enemies that join the encounter.
+    #[serde(default)]
+    summon: Vec<String>,
+    #[serde(default = "default_weight")]
+    weight: u32,
+}
+
+fn default_weight() -> u32 {
+    1
+}
+
+impl MoveDef {
+    fn build(&self, defined: &[(String, Enemy)]) -> Result<EnemyMove, String> {
+        if self.weight == 0 {
+            return Err("weight must be positive".to_string());
+        }
+        let effects = build_effects("effects", &self.effects, false)?;
+        let summons = self.summon.iter().try_fold(FpVec::new(), |summons, key| {
+            defined
+                .iter()
+                .find(|(k, _)| k == key)
+                .map(|(_, enemy)| summons.push(enemy.clone()))
+                .ok_or_else(|| format!("summoned enemy '{}' must be defined before it", key))
+        })?;
+        Ok(EnemyMove {
+            effects,
+            summons,
+            ..EnemyMove::new(&self.name).weight(self.weight)
+        })
+    }
+}
+
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+enum PatternDef {
+    #[default]
+    Cycle,
+    Weighted,
+}
+
+impl PatternDef {
+    fn build(&self) -> MovePattern {
+        match self {
+            PatternDef::Cycle => MovePattern::Cycle,
+            PatternDef::Weighted => MovePattern::Weighted,
+        }
+    }
+}
+
+fn build_effects(
+    list: &str,
+    defs: &[EffectDef],
+    allow_discard: bool,
+) -> Result<FpVec<GameEffect>, String> {
+    defs.iter()
+        .enumerate()
+        .try_fold(FpVec::new(), |effects, (idx, def)| {
+            def.build(allow_discard)
+                .map(|eff| effects.push(eff))
+                .map_err(|reason| format!("{}[{}] ('{}'): {}", list, idx, def.name, reason))
+        })
+}
+
+// Hooks are kept in the order of their phases.
+fn build_phase_effects(
+    defs: &BTreeMap<PhaseDef, Vec<EffectDef>>,
+) -> Result<FpVec<PhaseHook>, String> {
+    defs.iter().try_fold(FpVec::new(), |hooks, (phase, defs)| {
+        let phase = phase.build();
+        let list = format!("phase_effects.{}", phase.description().replace(' ', "_"));
+        build_effects(&list, defs, false).map(|effects| {
+            hooks.extend(FpVec::from_vec(
+                effects
+                    .inner
+                    .into_iter()
+                    .map(|effect| PhaseHook::new(phase, effect))
+                    .collect(),
+            ))
+        })
+    })
+}
+
+#[derive(Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+enum PhaseDef {
+    GameStart,
+    PlayerUpkeep,
+    PlayerMain,
+    PlayerEnd,
+    EnemyUpkeep,
+    EnemyAction,
+    EnemyEnd,
+    Cleanup,
+}
+
+impl PhaseDef {
+    fn build(&self) -> Phase {
+        match self {
+            PhaseDef::GameStart => Phase::GameStart,
+            PhaseDef::PlayerUpkeep => Phase::PlayerUpkeep,
+            PhaseDef::PlayerMain => Phase::PlayerMain,
+            PhaseDef::PlayerEnd => Phase::PlayerEnd,
+            PhaseDef::EnemyUpkeep => Phase::EnemyUpkeep,
+            PhaseDef::EnemyAction => Phase::EnemyAction,
+            PhaseDef::EnemyEnd => Phase::EnemyEnd,
+            PhaseDef::Cleanup => Phase::Cleanup,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct EffectDef {
+    name: String,
+    target: TargetDef,
+    #[serde(default)]
+    condition: Option<ConditionDef>,
+    /// A condition in its text form, instead of a `condition` table.
+    #[serde(default)]
+    when: Option<String>,
+    #[serde(default)]
+    effect: Option<EffectTypeDef>,
+    #[serde(default)]
+    discard: bool,
+    #[serde(default)]
+    exile: bool,
+    #[serde(default)]
+    draw: Option<u32>,
+}
+
+impl EffectDef {
+    fn build(&self, allow_discard: bool) -> Result<GameEffect, String> {
+        let kinds = [
+            self.effect.is_some(),
+            self.discard,
+            self.exile,
+            self.draw.is_some(),
+        ];
+        if kinds.iter().filter(|given| **given).count() != 1 {
+            return Err(
+                "exactly one of `effect`, `discard`, `exile` or `draw` must be given".to_string(),
+            );
+        }
+        if (self.condition.is_some() || self.when.is_some()) && self.effect.is_none() {
+            return Err("only an `effect` can have a `condition`".to_string());
+        }
+        if (self.discard || self.exile) && !allow_discard {
+            return Err("discard and exile are only allowed in play_card_effects".to_string());
+        }
+        let condition = match (&self.condition, &self.when) {
+            (Some(_), Some(_)) => {
+                return Err("only one of `condition` or `when` can be given".to_string())
+            }
+            (Some(cond), None) => Some(cond.build()?),
+            (None, Some(text)) => Some(
+                EffectCondition::parse(text)
+                    .map_err(|reason| format!("invalid `when` '{}': {}", text, reason))?,
+            ),
+            (None, None) => None,
+        };
+        let trigger = match (condition, &self.effect, self.draw) {
+            (None, Some(eff), _) => EffectTrigger::Always(eff.build()?),
+            (Some(cond), Some(eff), _) => EffectTrigger::Condition(cond, eff.build()?),
+            (_, None, Some(count)) => EffectTrigger::Draw(count),
+            (_, None, None) if self.discard => EffectTrigger::Discard(String::new()),
+            (_, None, None) => EffectTrigger::Exile(String::new()),
+        };
+        Ok(GameEffect {
+            name: self.name.clone(),
+            target: self.target.build(),
+            effect: trigger,
+        })
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum TargetDef {
+    Player,
+    Ally,
+    AllPlayers,
+    Enemy,
+    AllEnemies,
+}
+
+impl TargetDef {
+    fn build(&self) -> EffectTarget {
+        match self {
+            TargetDef::Player => EffectTarget::Player,
+            TargetDef::Ally => EffectTarget::Ally,
+            TargetDef::AllPlayers => EffectTarget::AllPlayers,
+            TargetDef::Enemy => EffectTarget::Enemy,
+            TargetDef::AllEnemies => EffectTarget::AllEnemies,
+        }
+    }
+}
+
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+enum TargetingDef {
+    #[default]
+    Random,
+    Weakest,
+    First,
+}
+
+impl TargetingDef {
+    fn build(&self) -> PlayerTargeting {
+        match self {
+            TargetingDef::Random => PlayerTargeting::Random,
+            TargetingDef::Weakest => PlayerTargeting::Weakest,
+            TargetingDef::First => PlayerTargeting::First,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+enum ConditionDef {
+    PlayerHasCardWithElement {
+        element: ElementDef,
+        #[serde(default)]
+        zone: ZoneDef,
+    },
+    PlayerHasNoCardWithElement {
+        element: ElementDef,
+        #[serde(default)]
+        zone: ZoneDef,
+    },
+    PlayerPlaysCardWithElement {
+        element: ElementDef,
+    },
+    EnemyHitPointsBelow {
+        hit_points: i32,
+    },
+    EnemyHitPointsBelowPercent {
+        percent: f64,
+    },
+    EnemyHasEnchantment {
+        enchantment: EnchantmentDef,
+    },
+    EnemySkippedLastTurn,
+    EnemyTookElementDamage {
+        element: ElementDef,
+    },
+    All {
+        of: Vec<ConditionDef>,
+    },
+    Any {
+        of: Vec<ConditionDef>,
+    },
+    Not {
+        condition: Box<ConditionDef>,
+    },
+}
+
+impl ConditionDef {
+    fn build(&self) -> Result<EffectCondition, String> {
+        let condition = match self {
+            ConditionDef::PlayerHasCardWithElement { element, zone } => {
+                EffectCondition::PlayerHasCardWithElement(zone.build(), element.build())
+            }
+            ConditionDef::PlayerHasNoCardWithElement { element, zone } => {
+                EffectCondition::PlayerHasNoCardWithElement(zone.build(), element.build())
+            }
+            ConditionDef::PlayerPlaysCardWithElement { element } => {
+                EffectCondition::PlayerPlaysCardWithElement(element.build())
+            }
+            ConditionDef::EnemyHitPointsBelow { hit_points } => {
+                EffectCondition::EnemyHitPointsBelow(*hit_points)
+            }
+            ConditionDef::EnemyHitPointsBelowPercent { percent }
+                if !(0.0..=1.0).contains(percent) =>
+            {
+                return Err(format!(
+                    "enemy_hit_points_below_percent must be between 0.0 and 1.0, got {}",
+                    percent
+                ))
+            }
+            ConditionDef::EnemyHitPointsBelowPercent { percent } => {
+                EffectCondition::EnemyHitPointsBelowPercent(*percent)
+            }
+            ConditionDef::EnemyHasEnchantment { enchantment } => {
+                EffectCondition::EnemyHasEnchantment(enchantment.build())
+            }
+            ConditionDef::EnemySkippedLastTurn => EffectCondition::EnemySkippedLastTurn,
+            ConditionDef::EnemyTookElementDamage { element } => {
+                EffectCondition::EnemyTookElementDamage(element.build())
+            }
+            ConditionDef::All { of } => EffectCondition::All(
+                of.iter()
+                    .map(|cond| cond.build())
+                    .collect::<Result<_, _>>()?,
+            ),
+            ConditionDef::Any { of } => EffectCondition::Any(
+                of.iter()
+                    .map(|cond| cond.build())
+                    .collect::<Result<_, _>>()?,
+            ),
+            ConditionDef::Not { condition } => EffectCondition::Not(Box::new(condition.build()?)),
+        };
+        Ok(condition)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+enum EffectTypeDef {
+    Damage {
+        element: ElementDef,
+        amount: i32,
+    },
+    LifeAdjust {
+        amount: i32,
+    },
+    Enchantment {
+        enchantment: EnchantmentDef,
+    },
+    ReplaceEnchantment {
+        enchantment: EnchantmentDef,
+        with: EnchantmentDef,
+    },
+    RemoveEnchantment {
+        enchantment: EnchantmentDef,
+    },
+    Dispel,
+    PercentDamage {
+        percent: f64,
+    },
+    SkipTurn,
+    Status {
+        status: StatusDef,
+    },
+}
+
+impl EffectTypeDef {
+    fn build(&self) -> Result<EffectType, String> {
+        match self {
+            EffectTypeDef::Damage { element, amount } => {
+                Ok(EffectType::Damage(Damage::new(element.build(), *amount)))
+            }
+            EffectTypeDef::LifeAdjust { amount } => Ok(EffectType::LifeAdjust(*amount)),
+            EffectTypeDef::Enchantment { enchantment } => {
+                Ok(EffectType::Enchantment(enchantment.build()))
+            }
+            EffectTypeDef::ReplaceEnchantment { enchantment, with } => Ok(
+                EffectType::ReplaceEnchantment(enchantment.build(), with.build()),
+            ),
+            EffectTypeDef::RemoveEnchantment { enchantment } => {
+                Ok(EffectType::RemoveEnchantment(enchantment.build()))
+            }
+            EffectTypeDef::Dispel => Ok(EffectType::Dispel),
+            EffectTypeDef::PercentDamage { percent } if !(0.0..=1.0).contains(percent) => {
+                Err(format!(
+                    "percent_damage must be between 0.0 and 1.0, got {}",
+                    percent
+                ))
+            }
+            EffectTypeDef::PercentDamage { percent } => Ok(EffectType::PercentDamage(*percent)),
+            EffectTypeDef::SkipTurn => Ok(EffectType::SkipTurn),
+            EffectTypeDef::Status { status } => Ok(EffectType::Status(status.build()?)),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct StatusDef {
+    kind: StatusKindDef,
+    #[serde(default = "default_stacks")]
+    stacks: i32,
+    #[serde(default = "default_turns")]
+    turns: u32,
+    #[serde(default)]
+    tick: Option<TickDef>,
+    /// The element of a `vulnerable` status.
+    #[serde(default)]
+    element: Option<ElementDef>,
+    /// The enchantment of an `enchanted` status.
+    #[serde(default)]
+    enchantment: Option<EnchantmentDef>,
+}
+
+fn default_stacks() -> i32 {
+    1
+}
+
+fn default_turns() -> u32 {
+    1
+}
+
+impl StatusDef {
+    fn build(&self) -> Result<Status, String> {
+        let kind = match (&self.kind, &self.element, &self.enchantment) {
+            (StatusKindDef::Burn, None, None) => StatusKind::Burn,
+            (StatusKindDef::Poison, None, None) => StatusKind::Poison,
+            (StatusKindDef::Stun, None, None) => StatusKind::Stun,
+            (StatusKindDef::Regeneration, None, None) => StatusKind::Regeneration,
+            (StatusKindDef::Vulnerable, Some(element), None) => {
+                StatusKind::Vulnerable(element.build())
+            }
+            (StatusKindDef::Enchanted, None, Some(enchantment)) => {
+                StatusKind::Enchanted(enchantment.build())
+            }
+            (StatusKindDef::Vulnerable, _, _) => {
+                return Err("a vulnerable status needs an `element` and nothing else".to_string())
+            }
+            (StatusKindDef::Enchanted, _, _) => {
+                return Err(
+                    "an enchanted status needs an `enchantment` and nothing else".to_string(),
+                )
+            }
+            _ => {
+                return Err(
+                    "only vulnerable and enchanted statuses take an element or enchantment"
+                        .to_string(),
+                )
+            }
+        };
+        if self.stacks <= 0 || self.turns == 0 {
+            return Err("status stacks and turns must be positive".to_string());
+        }
+        let status = Status::new(kind, self.stacks, self.turns);
+        Ok(match &self.tick {
+            Some(TickDef::OnStart) => status.ticking(Tick::OnStart),
+            Some(TickDef::OnEnd) => status.ticking(Tick::OnEnd),
+            None => status,
+        })
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum StatusKindDef {
+    Burn,
+    Poison,
+    Stun,
+    Regeneration,
+    Vulnerable,
+    Enchanted,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum TickDef {
+    OnStart,
+    OnEnd,
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+enum EnchantmentDef {
+    SpellDamageAdjust {
+        element: ElementDef,
+        amount: i32,
+    },
+    ShieldDamage {
+        amount: i32,
+    },
+    LifeAdjPerTurn {
+        amount: i32,
+    },
+    SpellElementForbidden {
+        element: ElementDef,
+    },
+    PowerCostAdjust {
+        element: ElementDef,
+        amount: i32,
+    },
+    ElementResistance {
+        element: ElementDef,
+        adjustment: AdjustmentDef,
+    },
+}
+
+impl EnchantmentDef {
+    fn build(&self) -> Enchantment {
+        match self {
+            EnchantmentDef::SpellDamageAdjust { element, amount } => {
+                Enchantment::SpellDamageAdjust(element.build(), *amount)
+            }
+            EnchantmentDef::ShieldDamage { amount } => Enchantment::ShieldDamage(*amount),
+            EnchantmentDef::LifeAdjPerTurn { amount } => Enchantment::LifeAdjPerTurn(*amount),
+            EnchantmentDef::SpellElementForbidden { element } => {
+                Enchantment::SpellElementForbidden(element.build())
+            }
+            EnchantmentDef::PowerCostAdjust { element, amount } => {
+                Enchantment::PowerCostAdjust(PowerCostAdjust {
+                    card_type: element.build(),
+                    amount: *amount,
+                })
+            }
+            EnchantmentDef::ElementResistance {
+                element,
+                adjustment,
+            } => Enchantment::ElementResistance(element.build(), adjustment.build()),
+        }
+    }
+}
+
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+enum ZoneDef {
+    Library,
+    #[default]
+    Hand,
+    Discard,
+    Exile,
+}
+
+impl ZoneDef {
+    fn build(&self) -> CardZone {
+        match self {
+            ZoneDef::Library => CardZone::Library,
+            ZoneDef::Hand => CardZone::Hand,
+            ZoneDef::Discard => CardZone::Discard,
+            ZoneDef::Exile => CardZone::Exile,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum ElementDef {
+    Wind,
+    Land,
+    Water,
+    #[serde(rename = "none")]
+    NoElement,
+}
+
+impl ElementDef {
+    fn build(&self) -> ElementType {
+        match self {
+            ElementDef::Wind => ElementType::Wind,
+            ElementDef::Land => ElementType::Land,
+            ElementDef::Water => ElementType::Water,
+            ElementDef::NoElement => ElementType::NoElement,
+        }
+    }
+}
+
+#[derive(Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+struct DefenseDef {
+    #[serde(default)]
+    wind: AdjustmentDef,
+    #[serde(default)]
+    water: AdjustmentDef,
+    #[serde(default)]
+    land: AdjustmentDef,
+    #[serde(default)]
+    any: AdjustmentDef,
+}
+
+impl DefenseDef {
+    fn build(&self) -> DefenseProps {
+        DefenseProps {
+            wind: self.wind.build(),
+            water: self.water.build(),
+            land: self.land.build(),
+            any: self.any.build(),
+        }
+    }
+}
+
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+enum AdjustmentDef {
+    Percent(f64),
+    Absolute(i32),
+    #[default]
+    Normal,
+}
+
+impl AdjustmentDef {
+    fn build(&self) -> DamageAdjustment {
+        match self {
+            AdjustmentDef::Percent(pct) => DamageAdjustment::Percent(*pct),
+            AdjustmentDef::Absolute(amt) => DamageAdjustment::Absolute(*amt),
+            AdjustmentDef::Normal => DamageAdjustment::Normal,
+        }
+    }
+}
@@ -0,0 +1,238 @@
+use crate::damage::DamageRules;
+use crate::events::GameEvent;
+use crate::fp_vec::FpVec;
+use crate::status::{self, Status};
+use crate::{
+    Damage, DefenseProps, EffectCondition, EffectTarget, EffectTrigger, EffectType, Enchantment,
+};
+use log::debug;
+
+/// Anything effects resolve against; implementors only say where vitals and defenses live.
+pub trait Combatant: Sized {
+    /// Who the combatant's events are recorded against before being pinned down.
+    const TARGET: EffectTarget;
+
+    fn vitals(&self) -> Vitals;
+    fn with_vitals(self, vitals: Vitals) -> Self;
+    fn defense(&self) -> DefenseProps;
+
+    /// `target` pinned down to the combatant at `idx`.
+    fn pinned(target: EffectTarget, idx: usize) -> EffectTarget;
+
+    fn is_standing(&self) -> bool {
+        self.vitals().hit_points > 0
+    }
+
+    /// The enchantments in effect, including those granted by statuses.
+    fn enchantments(&self) -> FpVec<Enchantment> {
+        self.vitals().enchantments()
+    }
+
+    /// The damage `damage` would do after going through `rules`.
+    fn adjusted_damage(&self, damage: &Damage, rules: &DamageRules) -> i32 {
+        let vitals = self.vitals();
+        rules
+            .resolve(
+                damage,
+                &self.defense(),
+                &vitals.enchantments(),
+                &vitals.statuses,
+            )
+            .0
+    }
+
+    fn apply_effect(self, effect: EffectType, rules: &DamageRules) -> (Self, FpVec<GameEvent>) {
+        let (vitals, events) =
+            apply_effect(self.vitals(), &self.defense(), effect, Self::TARGET, rules);
+        (self.with_vitals(vitals), events)
+    }
+
+    /// Resolves `Always` triggers, and `Condition` ones whose condition `met` accepts.
+    fn resolve_trigger<F>(
+        self,
+        trigger: EffectTrigger,
+        rules: &DamageRules,
+        met: F,
+    ) -> (Self, FpVec<GameEvent>)
+    where
+        F: FnOnce(&Self, &EffectCondition) -> bool,
+    {
+        match trigger {
+            EffectTrigger::Always(effect) => self.apply_effect(effect, rules),
+            EffectTrigger::Condition(cond, effect) if met(&self, &cond) => {
+                self.apply_effect(effect, rules)
+            }
+            trigger => {
+                debug!(
+                    "{} does not trigger: {}",
+                    Self::TARGET.description(),
+                    trigger.description()
+                );
+                (self, FpVec::new())
+            }
+        }
+    }
+}
+
+/// The state every effect resolves against, whoever it lands on.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Vitals {
+    pub hit_points: i32,
+    pub current_activated_effects: FpVec<Enchantment>,
+    pub statuses: FpVec<Status>,
+}
+
+impl Vitals {
+    /// The enchantments in effect, including those granted by statuses.
+    pub fn enchantments(&self) -> FpVec<Enchantment> {
+        self.current_activated_effects
+            .clone()
+            .extend(status::enchantments(&self.statuses))
+    }
+}
+
+// Resolves `effect` against `vitals`, recording events against `target`.
+fn apply_effect(
+    vitals: Vitals,
+    defense: &DefenseProps,
+    effect: EffectType,
+    target: EffectTarget,
+    rules: &DamageRules,
+) -> (Vitals, FpVec<GameEvent>) {
+    match effect {
+        EffectType::Damage(dmg) => take_damage(vitals, defense, dmg, target, rules),
+        EffectType::PercentDamage(pct) => {
+            let amount = ((vitals.hit_points as f64) * pct).floor() as i32;
+            take_damage(vitals, defense, Damage::raw(amount), target, rules)
+        }
+        EffectType::LifeAdjust(amt) => (
+            Vitals {
+                hit_points: vitals.hit_points + amt,
+                ..vitals
+            },
+            FpVec::from_vec(vec![GameEvent::LifeAdjusted {
+                target,
+                amount: amt,
+            }]),
+        ),
+        EffectType::SkipTurn => apply_effect(
+            vitals,
+            defense,
+            EffectType::Status(Status::stun(1)),
+            target,
+            rules,
+        ),
+        EffectType::Status(status) => (
+            Vitals {
+                statuses: status::apply(vitals.statuses, status.clone()),
+                ..vitals
+            },
+            FpVec::from_vec(vec![GameEvent::StatusApplied { target, status }]),
+        ),
+        EffectType::Dispel => {
+            let (current_activated_effects, events) =
+                Enchantment::resolve(vitals.current_activated_effects, &effect, target.clone());
+            let (statuses, stripped) = status::dispel(vitals.statuses);
+            let expired = stripped
+                .inner
+                .into_iter()
+                .map(|status| GameEvent::StatusExpired {
+                    target: target.clone(),
+                    status,
+                })
+                .collect();
+            (
+                Vitals {
+                    current_activated_effects,
+                    statuses,
+                    ..vitals
+                },
+                events.extend(FpVec::from_vec(expired)),
+            )
+        }
+        EffectType::Enchantment(_)
+        | EffectType::ReplaceEnchantment(_, _)
+        | EffectType::RemoveEnchantment(_) => {
+            let (current_activated_effects, events) =
+                Enchantment::resolve(vitals.current_activated_effects, &effect, target);
+            (
+                Vitals {
+                    current_activated_effects,
+                    ..vitals
+                },
+                events,
+            )
+        }
+    }
+}
+
+fn take_damage(
+    vitals: Vitals,
+    defense: &DefenseProps,
+    damage: Damage,
+    target: EffectTarget,
+    rules: &DamageRules,
+) -> (Vitals, FpVec<GameEvent>) {
+    let (amount, trace) = rules.resolve(&damage, defense, &vitals.enchantments(), &vitals.statuses);
+    let adjusted = trace
+        .into_iter()
+        .map(|(step, amount)| GameEvent::DamageAdjusted {
+            target: target.clone(),
+            step,
+            amount,
+        })
+        .collect();
+    let events = FpVec::from_vec(adjusted).push(GameEvent::DamageDealt {
+        target,
+        element: damage.element_type,
+        raw: damage.amount,
+        adjusted: amount,
+    });
+    (
+        Vitals {
+            hit_points: vitals.hit_points - amount,
+            ..vitals
+        },
+        events,
+    )
+}
+
+/// The positions of the combatants still standing.
+pub fn standing<C: Combatant>(combatants: &FpVec<C>) -> Vec<usize> {
+    combatants
+        .inner
+        .iter()
+        .enumerate()
+        .filter(|(_, combatant)| combatant.is_standing())
+        .map(|(idx, _)| idx)
+        .collect()
+}
+
+/// The first combatant still standing, or the first one if all are down.
+pub fn first_standing<C: Combatant>(combatants: &FpVec<C>) -> usize {
+    standing(combatants).first().copied().unwrap_or(0)
+}
+
+/// Replaces the combatant at `idx` with `update`'s result, pinning its events there.
+pub fn update_at<C, F>(combatants: FpVec<C>, idx: usize, update: F) -> (FpVec<C>, FpVec<GameEvent>)
+where
+    C: Combatant,
+    F: FnOnce(C) -> (C, FpVec<GameEvent>),
+{
+    let (combatants, events, _) = combatants.inner.into_iter().enumerate().fold(
+        (FpVec::new(), FpVec::new(), Some(update)),
+        |(combatants, events, update), (combatant_idx, combatant)| match update {
+            Some(update) if combatant_idx == idx => {
+                let (combatant, events) = update(combatant);
+                (combatants.push(combatant), events, None)
+            }
+            update => (combatants.push(combatant), events, update),
+        },
+    );
+    let events = events
+        .inner
+        .into_iter()
+        .map(|event| event.map_target(|target| C::pinned(target, idx)))
+        .collect();
+    (combatants, FpVec::from_vec(events))
+}
@@ -0,0 +1,394 @@
+use crate::enemy::Enemy;
+use crate::player::{Player, PlayerCard};
+use crate::{CardZone, EffectCondition, ElementType};
+use serde::{Deserialize, Serialize};
+
+/// A number about the game a condition can compare.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Quantity {
+    PlayerHitPoints,
+    EnemyHitPoints,
+    TurnNumber,
+    /// The turn number modulo a count, for every-other-turn effects.
+    TurnModulo(u32),
+    /// Cards in one of the player's zones.
+    CardsIn(CardZone),
+    /// Cards of an element in one of the player's zones.
+    ElementCardsIn(CardZone, ElementType),
+    /// Cards the player has played this turn.
+    CardsPlayed,
+}
+
+impl Quantity {
+    pub fn description(&self) -> String {
+        match self {
+            Quantity::PlayerHitPoints => "PLAYER_HP".to_string(),
+            Quantity::EnemyHitPoints => "ENEMY_HP".to_string(),
+            Quantity::TurnNumber => "TURN".to_string(),
+            Quantity::TurnModulo(count) => format!("TURN % {}", count),
+            Quantity::CardsIn(zone) => format!("CARDS [{}]", zone.description()),
+            Quantity::ElementCardsIn(zone, el) => {
+                format!("CARDS [{}] IN [{}]", el.description(), zone.description())
+            }
+            Quantity::CardsPlayed => "CARDS_PLAYED".to_string(),
+        }
+    }
+
+    pub fn value(&self, situation: &Situation) -> i32 {
+        match self {
+            Quantity::PlayerHitPoints => situation.player.hit_points,
+            Quantity::EnemyHitPoints => situation.enemy.hit_points,
+            Quantity::TurnNumber => situation.turn_number as i32,
+            Quantity::TurnModulo(count) => (situation.turn_number % (*count).max(1)) as i32,
+            Quantity::CardsIn(zone) => situation.player.zone(zone).inner.len() as i32,
+            Quantity::ElementCardsIn(zone, el) => situation
+                .player
+                .zone(zone)
+                .inner
+                .iter()
+                .filter(|card| card.element == *el)
+                .count() as i32,
+            Quantity::CardsPlayed => situation.cards_played as i32,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Comparison {
+    Less,
+    AtMost,
+    Equal,
+    NotEqual,
+    AtLeast,
+    Greater,
+}
+
+impl Comparison {
+    pub fn description(&self) -> String {
+        match self {
+            Comparison::Less => "<".to_string(),
+            Comparison::AtMost => "<=".to_string(),
+            Comparison::Equal => "==".to_string(),
+            Comparison::NotEqual => "!=".to_string(),
+            Comparison::AtLeast => ">=".to_string(),
+            Comparison::Greater => ">".to_string(),
+        }
+    }
+
+    pub fn holds(&self, left: i32, right: i32) -> bool {
+        match self {
+            Comparison::Less => left < right,
+            Comparison::AtMost => left <= right,
+            Comparison::Equal => left == right,
+            Comparison::NotEqual => left != right,
+            Comparison::AtLeast => left >= right,
+            Comparison::Greater => left > right,
+        }
+    }
+}
+
+/// What a condition is checked against: player, enemy, turn and any card being played.
+#[derive(Debug, Clone)]
+pub struct Situation<'a> {
+    pub player: &'a Player,
+    pub enemy: &'a Enemy,
+    pub turn_number: u32,
+    pub card: Option<&'a PlayerCard>,
+    pub cards_played: u32,
+}
+
+impl<'a> Situation<'a> {
+    pub fn new(player: &'a Player, enemy: &'a Enemy, turn_number: u32) -> Self {
+        Self {
+            player,
+            enemy,
+            turn_number,
+            card: None,
+            cards_played: player.cards_played,
+        }
+    }
+
+    /// The situation as `card` is played, `cards_played` counting it.
+    pub fn playing(self, card: &'a PlayerCard, cards_played: u32) -> Self {
+        Self {
+            card: Some(card),
+            cards_played,
+            ..self
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Word(String),
+    Number(i32),
+    Percent(f64),
+    Modulo,
+    Op(Comparison),
+    Open,
+    Close,
+    Comma,
+}
+
+fn tokenize(text: &str) -> Result<Vec<Token>, String> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut tokens = vec![];
+    let mut pos = 0;
+    while pos < chars.len() {
+        let c = chars[pos];
+        let start = pos;
+        pos += 1;
+        let token = match c {
+            _ if c.is_whitespace() => continue,
+            '(' => Token::Open,
+            ')' => Token::Close,
+            ',' => Token::Comma,
+            '%' => Token::Modulo,
+            '<' | '>' | '=' | '!' => {
+                let double = chars.get(pos) == Some(&'=');
+                if double {
+                    pos += 1;
+                }
+                Token::Op(match (c, double) {
+                    ('<', false) => Comparison::Less,
+                    ('<', true) => Comparison::AtMost,
+                    ('>', false) => Comparison::Greater,
+                    ('>', true) => Comparison::AtLeast,
+                    ('=', true) => Comparison::Equal,
+                    ('!', true) => Comparison::NotEqual,
+                    _ => return Err(format!("unknown operator '{}' at {}", c, start)),
+                })
+            }
+            _ if c.is_ascii_digit() || c == '-' => {
+                while pos < chars.len() && chars[pos].is_ascii_digit() {
+                    pos += 1;
+                }
+                let digits: String = chars[start..pos].iter().collect();
+                let number = digits
+                    .parse::<i32>()
+                    .map_err(|_| format!("invalid number '{}' at {}", digits, start))?;
+                if chars.get(pos) == Some(&'%') {
+                    pos += 1;
+                    Token::Percent(number as f64 / 100.0)
+                } else {
+                    Token::Number(number)
+                }
+            }
+            _ if c.is_ascii_alphabetic() => {
+                while pos < chars.len()
+                    && (chars[pos].is_ascii_alphanumeric()
+                        || chars[pos] == '_'
+                        || chars[pos] == '.')
+                {
+                    pos += 1;
+                }
+                Token::Word(chars[start..pos].iter().collect())
+            }
+            _ => return Err(format!("unexpected '{}' at {}", c, start)),
+        };
+        tokens.push(token);
+    }
+    Ok(tokens)
+}
+
+fn element(word: &str) -> Result<ElementType, String> {
+    match word {
+        "wind" => Ok(ElementType::Wind),
+        "land" => Ok(ElementType::Land),
+        "water" => Ok(ElementType::Water),
+        "none" => Ok(ElementType::NoElement),
+        _ => Err(format!("unknown element '{}'", word)),
+    }
+}
+
+fn zone(word: &str) -> Option<CardZone> {
+    match word {
+        "library" => Some(CardZone::Library),
+        "hand" => Some(CardZone::Hand),
+        "discard" => Some(CardZone::Discard),
+        "exile" => Some(CardZone::Exile),
+        _ => None,
+    }
+}
+
+fn quantity(word: &str) -> Result<Quantity, String> {
+    match word.split_once('.') {
+        _ if word == "player.hp" => Ok(Quantity::PlayerHitPoints),
+        _ if word == "enemy.hp" => Ok(Quantity::EnemyHitPoints),
+        _ if word == "turn" => Ok(Quantity::TurnNumber),
+        _ if word == "played" => Ok(Quantity::CardsPlayed),
+        Some((zone_word, element_word)) => match zone(zone_word) {
+            Some(zone) => Ok(Quantity::ElementCardsIn(zone, element(element_word)?)),
+            None => Err(format!("unknown quantity '{}'", word)),
+        },
+        None => zone(word)
+            .map(Quantity::CardsIn)
+            .ok_or_else(|| format!("unknown quantity '{}'", word)),
+    }
+}
+
+// Recursive descent, lowest precedence first: `or`, `and`, then `not` and parentheses.
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn expect(&mut self, expected: Token) -> Result<(), String> {
+        match self.next() {
+            Some(token) if token == expected => Ok(()),
+            other => Err(format!("expected {:?}, found {:?}", expected, other)),
+        }
+    }
+
+    fn at_word(&self, word: &str) -> bool {
+        matches!(self.peek(), Some(Token::Word(w)) if w == word)
+    }
+
+    fn any(&mut self) -> Result<EffectCondition, String> {
+        let first = self.all()?;
+        let mut rest = vec![];
+        while self.at_word("or") {
+            self.pos += 1;
+            rest.push(self.all()?);
+        }
+        if rest.is_empty() {
+            Ok(first)
+        } else {
+            Ok(EffectCondition::Any(
+                std::iter::once(first).chain(rest).collect(),
+            ))
+        }
+    }
+
+    fn all(&mut self) -> Result<EffectCondition, String> {
+        let first = self.unary()?;
+        let mut rest = vec![];
+        while self.at_word("and") {
+            self.pos += 1;
+            rest.push(self.unary()?);
+        }
+        if rest.is_empty() {
+            Ok(first)
+        } else {
+            Ok(EffectCondition::All(
+                std::iter::once(first).chain(rest).collect(),
+            ))
+        }
+    }
+
+    fn unary(&mut self) -> Result<EffectCondition, String> {
+        match self.next() {
+            Some(Token::Word(word)) if word == "not" => {
+                Ok(EffectCondition::Not(Box::new(self.unary()?)))
+            }
+            Some(Token::Open) => {
+                let inner = self.any()?;
+                self.expect(Token::Close)?;
+                Ok(inner)
+            }
+            Some(Token::Word(word)) => self.atom(word),
+            other => Err(format!("expected a condition, found {:?}", other)),
+        }
+    }
+
+    // The words in parentheses after a predicate.
+    fn arguments(&mut self) -> Result<Vec<String>, String> {
+        self.expect(Token::Open)?;
+        let mut args = vec![];
+        loop {
+            match self.next() {
+                Some(Token::Word(word)) => args.push(word),
+                other => return Err(format!("expected an argument, found {:?}", other)),
+            }
+            match self.next() {
+                Some(Token::Comma) => continue,
+                Some(Token::Close) => return Ok(args),
+                other => return Err(format!("expected ',' or ')', found {:?}", other)),
+            }
+        }
+    }
+
+    fn atom(&mut self, word: String) -> Result<EffectCondition, String> {
+        match word.as_str() {
+            "enemy.skipped" => return Ok(EffectCondition::EnemySkippedLastTurn),
+            "plays" | "has" | "lacks" | "enemy.took" => {
+                let args = self.arguments()?;
+                let (el, zone) = match args.as_slice() {
+                    [el] => (element(el)?, CardZone::Hand),
+                    [el, zone_word] if word == "has" || word == "lacks" => (
+                        element(el)?,
+                        zone(zone_word).ok_or_else(|| format!("unknown zone '{}'", zone_word))?,
+                    ),
+                    _ => return Err(format!("wrong number of arguments to '{}'", word)),
+                };
+                return Ok(match word.as_str() {
+                    "plays" => EffectCondition::PlayerPlaysCardWithElement(el),
+                    "has" => EffectCondition::PlayerHasCardWithElement(zone, el),
+                    "lacks" => EffectCondition::PlayerHasNoCardWithElement(zone, el),
+                    _ => EffectCondition::EnemyTookElementDamage(el),
+                });
+            }
+            _ => {}
+        }
+        let quantity = match (quantity(&word)?, self.peek()) {
+            (Quantity::TurnNumber, Some(Token::Modulo)) => {
+                self.pos += 1;
+                match self.next() {
+                    Some(Token::Number(count)) if count > 0 => Quantity::TurnModulo(count as u32),
+                    other => {
+                        return Err(format!(
+                            "expected a positive count after '%', found {:?}",
+                            other
+                        ))
+                    }
+                }
+            }
+            (quantity, _) => quantity,
+        };
+        let op = match self.next() {
+            Some(Token::Op(op)) => op,
+            other => {
+                return Err(format!(
+                    "expected a comparison after '{}', found {:?}",
+                    word, other
+                ))
+            }
+        };
+        match (self.next(), &quantity, &op) {
+            (Some(Token::Number(value)), _, _) => Ok(EffectCondition::Compare(quantity, op, value)),
+            (Some(Token::Percent(pct)), Quantity::EnemyHitPoints, Comparison::Less) => {
+                Ok(EffectCondition::EnemyHitPointsBelowPercent(pct))
+            }
+            (Some(Token::Percent(_)), _, _) => {
+                Err("a percentage can only be compared as 'enemy.hp < N%'".to_string())
+            }
+            (other, _, _) => Err(format!("expected a number, found {:?}", other)),
+        }
+    }
+}
+
+impl EffectCondition {
+    /// Parses a condition such as `turn >= 3 and (has(water) or not enemy.hp < 50%)`.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut parser = Parser {
+            tokens: tokenize(text)?,
+            pos: 0,
+        };
+        let condition = parser.any()?;
+        match parser.peek() {
+            None => Ok(condition),
+            Some(token) => Err(format!("unexpected {:?} after the condition", token)),
+        }
+    }
+}
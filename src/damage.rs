@@ -0,0 +1,162 @@
+use crate::fp_vec::FpVec;
+use crate::status::{self, Status};
+use crate::{Damage, DamageAdjustment, DefenseProps, ElementType, Enchantment};
+use serde::{Deserialize, Serialize};
+use std::cmp::max;
+
+/// A step of the damage pipeline, applied in the order a ruleset gives.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum DamageStep {
+    /// Bonuses the attacker adds to the damage.
+    AttackerBonus,
+    /// Percent adjustments for the damage's element.
+    ElementMultiplier,
+    /// Absolute adjustments for the damage's element, and vulnerabilities.
+    FlatResistance,
+    /// Shields, each taking off its amount.
+    Shield,
+    /// Damage below zero becomes zero.
+    Floor,
+}
+
+impl DamageStep {
+    pub fn description(&self) -> String {
+        match self {
+            DamageStep::AttackerBonus => "attacker bonus".to_string(),
+            DamageStep::ElementMultiplier => "element multiplier".to_string(),
+            DamageStep::FlatResistance => "flat resistance".to_string(),
+            DamageStep::Shield => "shields".to_string(),
+            DamageStep::Floor => "floor".to_string(),
+        }
+    }
+}
+
+/// How a step combines the defense against any element, against this one and resistances.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub enum Composition {
+    /// Every adjustment applies.
+    #[default]
+    Stack,
+    /// Only the adjustment leaving the least damage applies.
+    Lowest,
+}
+
+/// The ruleset for turning an attack into the damage its target takes.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DamageRules {
+    /// The steps applied to the base damage, in order.
+    pub steps: Vec<DamageStep>,
+    pub composition: Composition,
+}
+
+impl Default for DamageRules {
+    fn default() -> Self {
+        Self {
+            steps: vec![
+                DamageStep::AttackerBonus,
+                DamageStep::ElementMultiplier,
+                DamageStep::FlatResistance,
+                DamageStep::Shield,
+                DamageStep::Floor,
+            ],
+            composition: Composition::Stack,
+        }
+    }
+}
+
+impl DamageRules {
+    pub fn steps(self, steps: Vec<DamageStep>) -> Self {
+        Self { steps, ..self }
+    }
+
+    pub fn composition(self, composition: Composition) -> Self {
+        Self {
+            composition,
+            ..self
+        }
+    }
+
+    /// The damage `damage` does through the pipeline, with what it came to after each step.
+    pub fn resolve(
+        &self,
+        damage: &Damage,
+        defense: &DefenseProps,
+        enchantments: &FpVec<Enchantment>,
+        statuses: &FpVec<Status>,
+    ) -> (i32, Vec<(DamageStep, i32)>) {
+        let adjustments = adjustments(damage, defense, enchantments);
+        self.steps
+            .iter()
+            .fold((damage.amount, vec![]), |(amount, trace), step| {
+                let next = match step {
+                    DamageStep::AttackerBonus => amount + damage.bonus,
+                    DamageStep::ElementMultiplier => {
+                        let percents = adjustments.iter().filter_map(|adj| match adj {
+                            DamageAdjustment::Percent(pct) => Some(*pct),
+                            _ => None,
+                        });
+                        let percent = match self.composition {
+                            Composition::Stack => Some(percents.product()),
+                            Composition::Lowest => percents.reduce(f64::min),
+                        };
+                        percent.map_or(amount, |pct| (amount as f64 * pct).floor() as i32)
+                    }
+                    DamageStep::FlatResistance => {
+                        let flats = adjustments.iter().filter_map(|adj| match adj {
+                            DamageAdjustment::Absolute(flat) => Some(*flat),
+                            _ => None,
+                        });
+                        let flat = match self.composition {
+                            Composition::Stack => flats.sum(),
+                            Composition::Lowest => flats.min().unwrap_or(0),
+                        };
+                        amount + flat + status::vulnerability(statuses, &damage.element_type)
+                    }
+                    DamageStep::Shield => {
+                        enchantments
+                            .inner
+                            .iter()
+                            .fold(amount, |amount, eff| match eff {
+                                Enchantment::ShieldDamage(shield) => amount - shield,
+                                _ => amount,
+                            })
+                    }
+                    DamageStep::Floor => max(amount, 0),
+                };
+                if next == amount {
+                    (amount, trace)
+                } else {
+                    let trace = trace
+                        .into_iter()
+                        .chain(Some((step.clone(), next)))
+                        .collect();
+                    (next, trace)
+                }
+            })
+    }
+}
+
+// The defense and resistance adjustments that apply to `damage`.
+fn adjustments(
+    damage: &Damage,
+    defense: &DefenseProps,
+    enchantments: &FpVec<Enchantment>,
+) -> Vec<DamageAdjustment> {
+    let element = match damage.element_type {
+        ElementType::Wind => Some(defense.wind.clone()),
+        ElementType::Land => Some(defense.land.clone()),
+        ElementType::Water => Some(defense.water.clone()),
+        ElementType::NoElement => None,
+    };
+    let resistances = enchantments.inner.iter().filter_map(|eff| match eff {
+        Enchantment::ElementResistance(elem, adj) if *elem == damage.element_type => {
+            Some(adj.clone())
+        }
+        _ => None,
+    });
+    Some(defense.any.clone())
+        .into_iter()
+        .chain(element)
+        .chain(resistances)
+        .collect()
+}
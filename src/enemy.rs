@@ -1,25 +1,161 @@
+use crate::combatant::{Combatant, Vitals};
+use crate::condition::{Comparison, Quantity, Situation};
+use crate::damage::DamageRules;
+use crate::events::GameEvent;
 use crate::fp_vec::FpVec;
 use crate::game_effects::{Enchantments, EnemyEffects, OnCardPlayEffects};
+use crate::phase::{Phase, PhaseHook};
 use crate::player::Player;
+use crate::rng::GameRng;
+use crate::status::Status;
 use crate::{
-    game_effects::GameEffect, Damage, DamageAdjustment, DefenseProps, EffectTrigger, EffectType,
-    ElementType, Enchantment,
+    game_effects::GameEffect, DamageAdjustment, DefenseProps, EffectCondition, EffectTarget,
+    EffectTrigger, EffectType, ElementType, Enchantment,
 };
-use std::cmp::min;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone)]
+/// How an enemy picks which player to attack on its turn.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub enum PlayerTargeting {
+    #[default]
+    Random,
+    /// The standing player with the fewest hit points.
+    Weakest,
+    /// The first player still standing.
+    First,
+}
+
+impl PlayerTargeting {
+    /// The position of the player to attack; draws from `rng` only when there is a choice.
+    pub fn pick(&self, players: &FpVec<Player>, rng: &mut GameRng) -> usize {
+        let standing: Vec<(usize, &Player)> = players
+            .inner
+            .iter()
+            .enumerate()
+            .filter(|(_, player)| player.is_standing())
+            .collect();
+        match self {
+            _ if standing.len() <= 1 => standing.first().map_or(0, |(idx, _)| *idx),
+            PlayerTargeting::Random => standing[rng.gen_range(0, standing.len())].0,
+            PlayerTargeting::Weakest => standing
+                .iter()
+                .min_by_key(|(_, player)| player.hit_points)
+                .map_or(0, |(idx, _)| *idx),
+            PlayerTargeting::First => standing[0].0,
+        }
+    }
+}
+
+/// Something an enemy can do on its turn, besides its every-turn effects.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EnemyMove {
+    pub name: String,
+    pub effects: FpVec<GameEffect>,
+    /// Enemies that join the encounter when the move is made.
+    #[serde(default)]
+    pub summons: FpVec<Enemy>,
+    /// How often a weighted pattern picks this move, relative to the others.
+    #[serde(default = "default_weight")]
+    pub weight: u32,
+}
+
+fn default_weight() -> u32 {
+    1
+}
+
+impl EnemyMove {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            effects: FpVec::new(),
+            summons: FpVec::new(),
+            weight: 1,
+        }
+    }
+
+    pub fn effect(self, effect: GameEffect) -> Self {
+        Self {
+            effects: self.effects.push(effect),
+            ..self
+        }
+    }
+
+    pub fn summon(self, enemy: Enemy) -> Self {
+        Self {
+            summons: self.summons.push(enemy),
+            ..self
+        }
+    }
+
+    pub fn weight(self, weight: u32) -> Self {
+        Self { weight, ..self }
+    }
+
+    pub fn description(&self) -> String {
+        format!(
+            "{} [{}]",
+            self.name,
+            self.effects
+                .inner
+                .iter()
+                .map(|eff| eff.description())
+                .chain(
+                    self.summons
+                        .inner
+                        .iter()
+                        .map(|enemy| format!("Summon {}", enemy.name))
+                )
+                .collect::<Vec<String>>()
+                .join(", ")
+        )
+    }
+}
+
+/// How an enemy picks its next move.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub enum MovePattern {
+    /// The moves in order, starting over after the last.
+    #[default]
+    Cycle,
+    /// A random move each turn, by weight.
+    Weighted,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Enemy {
     pub name: String,
     pub hit_points: i32,
+    pub max_hit_points: i32,
     pub defense_props: DefenseProps,
-    pub skip_next_turn: bool,
     pub start_turn_effects: FpVec<GameEffect>,
     pub end_turn_effects: FpVec<GameEffect>,
-    pub temp_start_turn_effects: FpVec<GameEffect>,
     pub player_start_turn_effects: FpVec<GameEffect>,
+    #[serde(default)]
+    pub player_end_turn_effects: FpVec<GameEffect>,
     pub player_play_card_effects: FpVec<GameEffect>,
+    /// Effects that resolve as a phase begins.
+    #[serde(default)]
+    pub phase_effects: FpVec<PhaseHook>,
     pub enchantments: FpVec<GameEffect>,
     pub current_activated_effects: FpVec<Enchantment>,
+    #[serde(default)]
+    pub statuses: FpVec<Status>,
+    #[serde(default)]
+    pub targeting: PlayerTargeting,
+    #[serde(default)]
+    pub moves: FpVec<EnemyMove>,
+    #[serde(default)]
+    pub pattern: MovePattern,
+    /// The move the enemy will make next, shown to the players ahead of time.
+    #[serde(default)]
+    pub intent: Option<usize>,
+    /// Whether the enemy was stunned through its last turn.
+    #[serde(default)]
+    pub skipped_last_turn: bool,
+    /// The elements of the damage taken since the round began.
+    #[serde(default)]
+    pub damage_taken: FpVec<ElementType>,
 }
 
 impl Enemy {
@@ -27,25 +163,78 @@ impl Enemy {
         Self {
             name: name.to_string(),
             hit_points,
+            max_hit_points: hit_points,
             defense_props,
-            skip_next_turn: false,
             start_turn_effects: FpVec::new(),
             end_turn_effects: FpVec::from_vec(vec![EnemyEffects::attack(turn_damage)]),
-            temp_start_turn_effects: FpVec::new(),
             player_start_turn_effects: FpVec::new(),
+            player_end_turn_effects: FpVec::new(),
             player_play_card_effects: FpVec::new(),
+            phase_effects: FpVec::new(),
             enchantments: FpVec::new(),
             current_activated_effects: FpVec::new(),
+            statuses: FpVec::new(),
+            targeting: PlayerTargeting::Random,
+            moves: FpVec::new(),
+            pattern: MovePattern::Cycle,
+            intent: None,
+            skipped_last_turn: false,
+            damage_taken: FpVec::new(),
         }
     }
 
+    /// Drops the standard attack, for enemies that only act through moves.
+    pub fn end_turn_effects_cleared(self) -> Self {
+        Self {
+            end_turn_effects: FpVec::new(),
+            ..self
+        }
+    }
+
+    pub fn enemy_move(self, enemy_move: EnemyMove) -> Self {
+        Self {
+            moves: self.moves.push(enemy_move),
+            ..self
+        }
+    }
+
+    pub fn pattern(self, pattern: MovePattern) -> Self {
+        Self { pattern, ..self }
+    }
+
+    /// The move the enemy intends to make on its next turn.
+    pub fn intended_move(&self) -> Option<&EnemyMove> {
+        self.intent.and_then(|idx| self.moves.inner.get(idx))
+    }
+
+    /// Picks the next move; a weighted pattern only draws from `rng` when it has a choice.
+    pub fn choose_intent(self, rng: &mut GameRng) -> Self {
+        let count = self.moves.inner.len();
+        let intent = match self.pattern {
+            _ if count == 0 => None,
+            MovePattern::Cycle => Some(self.intent.map_or(0, |idx| (idx + 1) % count)),
+            MovePattern::Weighted if count == 1 => Some(0),
+            MovePattern::Weighted => {
+                let total: u32 = self.moves.inner.iter().map(|mv| mv.weight).sum();
+                let roll = rng.gen_range(0, total.max(1));
+                self.moves
+                    .inner
+                    .iter()
+                    .scan(0, |seen, mv| {
+                        *seen += mv.weight;
+                        Some(*seen)
+                    })
+                    .position(|seen| roll < seen)
+            }
+        };
+        Self { intent, ..self }
+    }
+
+    pub fn targeting(self, targeting: PlayerTargeting) -> Self {
+        Self { targeting, ..self }
+    }
+
     pub fn start_turn_effect(self, effect: GameEffect) -> Self {
-        self.enchantments
-            .inner
-            .iter()
-            .fold((), |card, eff| match eff {
-                _ => (),
-            });
         Self {
             start_turn_effects: self.start_turn_effects.push(effect),
             ..self
@@ -66,6 +255,13 @@ impl Enemy {
         }
     }
 
+    pub fn player_end_turn_effect(self, effect: GameEffect) -> Self {
+        Self {
+            player_end_turn_effects: self.player_end_turn_effects.push(effect),
+            ..self
+        }
+    }
+
     pub fn player_play_card_effect(self, effect: GameEffect) -> Self {
         Self {
             player_play_card_effects: self.player_play_card_effects.push(effect),
@@ -73,6 +269,13 @@ impl Enemy {
         }
     }
 
+    pub fn phase_effect(self, phase: Phase, effect: GameEffect) -> Self {
+        Self {
+            phase_effects: self.phase_effects.push(PhaseHook::new(phase, effect)),
+            ..self
+        }
+    }
+
     pub fn enchantment(self, effect: GameEffect) -> Self {
         Self {
             enchantments: self.enchantments.push(effect),
@@ -82,9 +285,13 @@ impl Enemy {
 
     pub fn description(&self) -> String {
         format!(
-            "{} - HP [{}]\n  * Start Turn Effects [{}]\n  * End Turn Effects [{}]\n  * Player Start Turn Effects [{}]\n  * Player Play Card Effects [{}]\n  * Current Enchantments [{}]",
+            "{} - HP [{}]{}\n  * Start Turn Effects [{}]\n  * End Turn Effects [{}]\n  * Player Start Turn Effects [{}]\n  * Player End Turn Effects [{}]\n  * Player Play Card Effects [{}]\n  * Phase Effects [{}]\n  * Current Enchantments [{}]\n  * Statuses [{}]",
             self.name,
             self.hit_points,
+            match self.intended_move() {
+                Some(intent) => format!("\n  * Intent: {}", intent.description()),
+                None => String::new(),
+            },
             self.start_turn_effects
                 .inner
                 .iter()
@@ -103,96 +310,83 @@ impl Enemy {
                 .map(|eff| eff.description())
                 .collect::<Vec<String>>()
                 .join(", "),
+            self.player_end_turn_effects
+                .inner
+                .iter()
+                .map(|eff| eff.description())
+                .collect::<Vec<String>>()
+                .join(", "),
             self.player_play_card_effects
                 .inner
                 .iter()
                 .map(|eff| eff.description())
                 .collect::<Vec<String>>()
                 .join(", "),
-            self.current_activated_effects
+            self.phase_effects
+                .inner
+                .iter()
+                .map(|hook| hook.description())
+                .collect::<Vec<String>>()
+                .join(", "),
+            self.enchantments()
                 .inner
                 .iter()
                 .map(|eff| eff.description())
                 .collect::<Vec<String>>()
                 .join(", "),
+            self.statuses
+                .inner
+                .iter()
+                .map(|status| status.description())
+                .collect::<Vec<String>>()
+                .join(", "),
         )
     }
 
-    pub fn start_turn(&self, player: &Player) -> FpVec<GameEffect> {
-        self.start_turn_effects.clone()
+    /// The enemy's start-of-turn effects followed by its intended move.
+    pub fn start_turn(&self, _player: &Player) -> FpVec<GameEffect> {
+        self.start_turn_effects.clone().extend(
+            self.intended_move()
+                .map_or(FpVec::new(), |intent| intent.effects.clone()),
+        )
     }
 
-    pub fn end_turn(&self, player: &Player) -> FpVec<GameEffect> {
+    pub fn end_turn(&self, _player: &Player) -> FpVec<GameEffect> {
         self.end_turn_effects.clone()
     }
 
-    pub fn trigger_effect(self, trigger: EffectTrigger, player: &Player) -> Self {
-        match trigger {
-            EffectTrigger::Always(effect) => self.apply_effect(effect),
-            EffectTrigger::Condition(cond, effect)
-                if cond.check_enemy(&self) && cond.check_player(player) =>
-            {
-                self.apply_effect(effect)
-            }
-            _ => self,
-        }
-    }
-
-    fn apply_effect(self, effect: EffectType) -> Self {
-        match effect {
-            EffectType::Damage(dmg) => self.take_damage(dmg),
-            EffectType::PercentDamage(pct) => {
-                let amount = ((self.hit_points as f64) * pct).floor() as i32;
-                self.take_damage(Damage {
-                    element_type: ElementType::NoElement,
-                    amount,
-                })
-            }
-            EffectType::LifeAdjust(amt) => {
-                println!("Emeny, {} HP", amt);
-                Self {
-                    hit_points: self.hit_points + amt,
-                    ..self
-                }
-            }
-            EffectType::SkipTurn => {
-                println!("Enemy Has to Skip Next Turn");
-                Self {
-                    skip_next_turn: true,
-                    temp_start_turn_effects: self.temp_start_turn_effects.push(GameEffect::enemy(
-                        "Skip Turn",
-                        EffectTrigger::Always(EffectType::SkipTurn),
-                    )),
-                    ..self
-                }
-            }
-            _ => Self { ..self },
-        }
+    pub fn trigger_effect(
+        self,
+        trigger: EffectTrigger,
+        player: &Player,
+        rules: &DamageRules,
+        turn_number: u32,
+    ) -> (Self, FpVec<GameEvent>) {
+        let (enemy, events) = self.resolve_trigger(trigger, rules, |enemy, cond| {
+            cond.check(&Situation::new(player, enemy, turn_number))
+        });
+        let damage_taken = events
+            .inner
+            .iter()
+            .fold(enemy.damage_taken.clone(), |taken, event| match event {
+                GameEvent::DamageDealt {
+                    element, adjusted, ..
+                } if *adjusted > 0 && !taken.inner.contains(element) => taken.push(element.clone()),
+                _ => taken,
+            });
+        (
+            Self {
+                damage_taken,
+                ..enemy
+            },
+            events,
+        )
     }
 
-    pub fn skip_turn(self) -> Self {
+    /// Forgets the damage taken last round.
+    pub fn begin_round(self) -> Self {
         Self {
-            skip_next_turn: false,
-            ..self
-        }
-    }
-
-    fn take_damage(self, damage: Damage) -> Self {
-        let raw_damage1 = self.defense_props.any.adjust_damage(damage.amount);
-        let raw_damage2 = match damage.element_type {
-            ElementType::Wind => self.defense_props.wind.adjust_damage(damage.amount),
-            ElementType::Land => self.defense_props.land.adjust_damage(damage.amount),
-            ElementType::Water => self.defense_props.water.adjust_damage(damage.amount),
-            ElementType::NoElement => damage.amount,
-        };
-        println!(
-            "Enemy takes damage: {}/{} ({} actual)",
-            damage.element_type.description(),
-            damage.amount,
-            min(raw_damage1, raw_damage2)
-        );
-        Enemy {
-            hit_points: self.hit_points - min(raw_damage1, raw_damage2),
+            damage_taken: FpVec::new(),
             ..self
         }
     }
@@ -352,6 +546,45 @@ impl Enemy {
         )
     }
 
+    pub fn rising_floods() -> Self {
+        Self::new(
+            "Rising Floods",
+            12,
+            DefenseProps {
+                wind: DamageAdjustment::Normal,
+                water: DamageAdjustment::Normal,
+                land: DamageAdjustment::Normal,
+                any: DamageAdjustment::Normal,
+            },
+            5,
+        )
+        .player_start_turn_effect(GameEffect::player(
+            "Rising Water",
+            EffectTrigger::Condition(
+                EffectCondition::Compare(Quantity::TurnModulo(2), Comparison::Equal, 0),
+                EffectType::Status(Status::enchanted(
+                    Enchantment::SpellElementForbidden(ElementType::Water),
+                    1,
+                )),
+            ),
+        ))
+    }
+
+    pub fn scorching_drought() -> Self {
+        Self::new(
+            "Scorching Drought",
+            25,
+            DefenseProps {
+                wind: DamageAdjustment::Normal,
+                water: DamageAdjustment::Absolute(1),
+                land: DamageAdjustment::Percent(0.0),
+                any: DamageAdjustment::Normal,
+            },
+            2,
+        )
+        .player_start_turn_effect(EnemyEffects::drain_player(1))
+    }
+
     pub fn tornado() -> Self {
         Self::new(
             "Tornado",
@@ -405,4 +638,172 @@ impl Enemy {
             1,
         ))
     }
+
+    pub fn chemical_leak() -> Self {
+        Self::new(
+            "Chemical Leak",
+            15,
+            DefenseProps {
+                wind: DamageAdjustment::Absolute(1),
+                water: DamageAdjustment::Normal,
+                land: DamageAdjustment::Normal,
+                any: DamageAdjustment::Normal,
+            },
+            1,
+        )
+        .enemy_move(EnemyMove::new("Seep").effect(EnemyEffects::afflict_player(Status::poison(3))))
+        .enemy_move(
+            EnemyMove::new("Corrode").effect(EnemyEffects::afflict_player(Status::vulnerable(
+                ElementType::NoElement,
+                1,
+                2,
+            ))),
+        )
+    }
+
+    pub fn acid_rain() -> Self {
+        Self::new(
+            "Acid Rain",
+            13,
+            DefenseProps {
+                wind: DamageAdjustment::Normal,
+                water: DamageAdjustment::Percent(0.5),
+                land: DamageAdjustment::Normal,
+                any: DamageAdjustment::Normal,
+            },
+            2,
+        )
+        .enemy_move(EnemyMove::new("Drizzle"))
+        .enemy_move(EnemyMove::new("Dissolve").effect(EnemyEffects::dispel_player()))
+    }
+
+    pub fn sinkhole() -> Self {
+        Self::new("Sinkhole", 16, DefenseProps::normal(), 1)
+            .enchantment(Enchantments::enemy_shield(1))
+            .enemy_move(EnemyMove::new("Swallow").effect(EnemyEffects::skip_player_turn()))
+            .enemy_move(EnemyMove::new("Collapse").effect(EnemyEffects::percent_attack(0.5)))
+    }
+
+    pub fn flash_flood() -> Self {
+        Self::new(
+            "Flash Flood",
+            12,
+            DefenseProps {
+                wind: DamageAdjustment::Normal,
+                water: DamageAdjustment::Percent(0.0),
+                land: DamageAdjustment::Normal,
+                any: DamageAdjustment::Normal,
+            },
+            0,
+        )
+        .end_turn_effects_cleared()
+        .end_turn_effect(EnemyEffects::elemental_attack(ElementType::Water, 4))
+    }
+
+    pub fn thunderhead() -> Self {
+        Self::new("Thunderhead", 16, DefenseProps::normal(), 2)
+            .end_turn_effect(EnemyEffects::enraged_attack(0.5, 3))
+            .end_turn_effect(EnemyEffects::retaliate(ElementType::Water, 2))
+    }
+
+    pub fn ember() -> Self {
+        Self::new(
+            "Ember",
+            4,
+            DefenseProps {
+                wind: DamageAdjustment::Normal,
+                water: DamageAdjustment::Absolute(1),
+                land: DamageAdjustment::Normal,
+                any: DamageAdjustment::Normal,
+            },
+            1,
+        )
+    }
+
+    pub fn wildfire() -> Self {
+        Self::new(
+            "Wildfire",
+            18,
+            DefenseProps {
+                wind: DamageAdjustment::Absolute(-1),
+                water: DamageAdjustment::Absolute(1),
+                land: DamageAdjustment::Normal,
+                any: DamageAdjustment::Normal,
+            },
+            0,
+        )
+        .end_turn_effects_cleared()
+        .enemy_move(EnemyMove::new("Smolder"))
+        .enemy_move(EnemyMove::new("Firestorm").effect(EnemyEffects::attack(9)))
+        .enemy_move(EnemyMove::new("Spread").summon(Self::ember()))
+    }
+
+    pub fn storm_cell() -> Self {
+        Self::new(
+            "Storm Cell",
+            5,
+            DefenseProps {
+                wind: DamageAdjustment::Percent(0.0),
+                water: DamageAdjustment::Normal,
+                land: DamageAdjustment::Normal,
+                any: DamageAdjustment::Normal,
+            },
+            2,
+        )
+    }
+
+    pub fn hurricane() -> Self {
+        Self::new(
+            "Hurricane",
+            24,
+            DefenseProps {
+                wind: DamageAdjustment::Percent(0.0),
+                water: DamageAdjustment::Normal,
+                land: DamageAdjustment::Absolute(-1),
+                any: DamageAdjustment::Normal,
+            },
+            0,
+        )
+        .end_turn_effects_cleared()
+        .pattern(MovePattern::Weighted)
+        .enemy_move(
+            EnemyMove::new("Gale")
+                .effect(EnemyEffects::attack(4))
+                .weight(3),
+        )
+        .enemy_move(EnemyMove::new("Eye of the Storm").effect(EnemyEffects::heal_self(5)))
+        .enemy_move(EnemyMove::new("Tangling Winds").effect(
+            Enchantments::player_elem_spell_damage_adj(ElementType::Wind, -1),
+        ))
+        .enemy_move(EnemyMove::new("Spawn Storm Cell").summon(Self::storm_cell()))
+    }
+}
+
+impl Combatant for Enemy {
+    const TARGET: EffectTarget = EffectTarget::Enemy;
+
+    fn vitals(&self) -> Vitals {
+        Vitals {
+            hit_points: self.hit_points,
+            current_activated_effects: self.current_activated_effects.clone(),
+            statuses: self.statuses.clone(),
+        }
+    }
+
+    fn with_vitals(self, vitals: Vitals) -> Self {
+        Self {
+            hit_points: vitals.hit_points,
+            current_activated_effects: vitals.current_activated_effects,
+            statuses: vitals.statuses,
+            ..self
+        }
+    }
+
+    fn defense(&self) -> DefenseProps {
+        self.defense_props.clone()
+    }
+
+    fn pinned(target: EffectTarget, idx: usize) -> EffectTarget {
+        target.aimed_at(idx)
+    }
 }
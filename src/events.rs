@@ -0,0 +1,257 @@
+use crate::damage::DamageStep;
+use crate::game::GameOutcome;
+use crate::status::Status;
+use crate::{EffectTarget, EffectType, ElementType, Enchantment};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum BlockReason {
+    Forbidden,
+    NotInHand,
+    NotEnoughPower { cost: i32, available: i32 },
+    TargetDown,
+    AllyDown,
+}
+
+impl BlockReason {
+    pub fn description(&self) -> String {
+        match self {
+            BlockReason::Forbidden => "forbidden".to_string(),
+            BlockReason::NotInHand => "not in hand".to_string(),
+            BlockReason::NotEnoughPower { cost, available } => {
+                format!("costs {} power, {} left", cost, available)
+            }
+            BlockReason::TargetDown => "target is down".to_string(),
+            BlockReason::AllyDown => "ally is down".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum GameEvent {
+    EnchantmentActivated {
+        target: EffectTarget,
+        enchantment: Enchantment,
+    },
+    EnchantmentRemoved {
+        target: EffectTarget,
+        enchantment: Enchantment,
+    },
+    CardDrawn {
+        card: String,
+    },
+    NoCardsToDraw,
+    DiscardReshuffled {
+        cards: usize,
+    },
+    CardPlayed {
+        card: String,
+    },
+    CardBlocked {
+        card: String,
+        reason: BlockReason,
+    },
+    CounterEffect {
+        target: EffectTarget,
+        element: ElementType,
+        effect: EffectType,
+    },
+    /// What damage to `target` came to after a step of the pipeline.
+    DamageAdjusted {
+        target: EffectTarget,
+        step: DamageStep,
+        amount: i32,
+    },
+    DamageDealt {
+        target: EffectTarget,
+        element: ElementType,
+        raw: i32,
+        adjusted: i32,
+    },
+    LifeAdjusted {
+        target: EffectTarget,
+        amount: i32,
+    },
+    CardDiscarded {
+        card: String,
+    },
+    CardExiled {
+        card: String,
+    },
+    StatusApplied {
+        target: EffectTarget,
+        status: Status,
+    },
+    StatusExpired {
+        target: EffectTarget,
+        status: Status,
+    },
+    TurnSkipped {
+        target: EffectTarget,
+    },
+    MoveMade {
+        target: EffectTarget,
+        name: String,
+    },
+    EnemySummoned {
+        target: EffectTarget,
+        name: String,
+    },
+    GameOver {
+        outcome: GameOutcome,
+    },
+}
+
+impl GameEvent {
+    /// Rewrites the event's target, pinning `Enemy` or `Player` down to a position.
+    pub fn map_target<F: Fn(EffectTarget) -> EffectTarget>(self, f: F) -> Self {
+        match self {
+            GameEvent::EnchantmentActivated {
+                target,
+                enchantment,
+            } => GameEvent::EnchantmentActivated {
+                target: f(target),
+                enchantment,
+            },
+            GameEvent::EnchantmentRemoved {
+                target,
+                enchantment,
+            } => GameEvent::EnchantmentRemoved {
+                target: f(target),
+                enchantment,
+            },
+            GameEvent::CounterEffect {
+                target,
+                element,
+                effect,
+            } => GameEvent::CounterEffect {
+                target: f(target),
+                element,
+                effect,
+            },
+            GameEvent::DamageAdjusted {
+                target,
+                step,
+                amount,
+            } => GameEvent::DamageAdjusted {
+                target: f(target),
+                step,
+                amount,
+            },
+            GameEvent::DamageDealt {
+                target,
+                element,
+                raw,
+                adjusted,
+            } => GameEvent::DamageDealt {
+                target: f(target),
+                element,
+                raw,
+                adjusted,
+            },
+            GameEvent::LifeAdjusted { target, amount } => GameEvent::LifeAdjusted {
+                target: f(target),
+                amount,
+            },
+            GameEvent::StatusApplied { target, status } => GameEvent::StatusApplied {
+                target: f(target),
+                status,
+            },
+            GameEvent::StatusExpired { target, status } => GameEvent::StatusExpired {
+                target: f(target),
+                status,
+            },
+            GameEvent::TurnSkipped { target } => GameEvent::TurnSkipped { target: f(target) },
+            GameEvent::MoveMade { target, name } => GameEvent::MoveMade {
+                target: f(target),
+                name,
+            },
+            event => event,
+        }
+    }
+
+    pub fn description(&self) -> String {
+        match self {
+            GameEvent::EnchantmentActivated {
+                target,
+                enchantment,
+            } => format!(
+                "{} enchantment active: {}",
+                target.description(),
+                enchantment.description()
+            ),
+            GameEvent::EnchantmentRemoved {
+                target,
+                enchantment,
+            } => format!(
+                "{} enchantment removed: {}",
+                target.description(),
+                enchantment.description()
+            ),
+            GameEvent::CardDrawn { card } => format!("Player draws {}", card),
+            GameEvent::NoCardsToDraw => "No cards left to draw".to_string(),
+            GameEvent::DiscardReshuffled { cards } => {
+                format!("Discard pile ({} cards) shuffled into library", cards)
+            }
+            GameEvent::CardPlayed { card } => format!("Play card: {}", card),
+            GameEvent::CardBlocked { card, reason } => {
+                format!("Cannot play card: {} ({})", card, reason.description())
+            }
+            GameEvent::CounterEffect {
+                target,
+                element,
+                effect,
+            } => format!(
+                "Casting a {} spell causes counter effect on {}: {}",
+                element.description(),
+                target.description(),
+                effect.description()
+            ),
+            GameEvent::DamageAdjusted {
+                target,
+                step,
+                amount,
+            } => format!(
+                "{} damage after {}: {}",
+                target.description(),
+                step.description(),
+                amount
+            ),
+            GameEvent::DamageDealt {
+                target,
+                element,
+                raw,
+                adjusted,
+            } => format!(
+                "{} takes damage: {}/{} ({} actual)",
+                target.description(),
+                element.description(),
+                raw,
+                adjusted
+            ),
+            GameEvent::LifeAdjusted { target, amount } => {
+                format!("{}, {} HP", target.description(), amount)
+            }
+            GameEvent::CardDiscarded { card } => format!("Discarding {}", card),
+            GameEvent::CardExiled { card } => format!("Exiling {}", card),
+            GameEvent::StatusApplied { target, status } => {
+                format!("{} gains {}", target.description(), status.description())
+            }
+            GameEvent::StatusExpired { target, status } => format!(
+                "{} is no longer affected by {}",
+                target.description(),
+                status.kind.description()
+            ),
+            GameEvent::TurnSkipped { target } => {
+                format!("{} skips their turn", target.description())
+            }
+            GameEvent::MoveMade { target, name } => {
+                format!("{} uses {}", target.description(), name)
+            }
+            GameEvent::EnemySummoned { target, name } => {
+                format!("{} joins the fight as {}", name, target.description())
+            }
+            GameEvent::GameOver { outcome } => format!("Game over: {}", outcome.description()),
+        }
+    }
+}
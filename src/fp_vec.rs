@@ -1,8 +1,16 @@
-#[derive(Debug, Clone)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FpVec<T> {
     pub inner: Vec<T>,
 }
 
+impl<T> Default for FpVec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<T> FpVec<T> {
     pub fn new() -> Self {
         Self { inner: vec![] }
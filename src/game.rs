@@ -1,52 +1,695 @@
+use crate::combatant::{self, Combatant, Vitals};
+use crate::condition::Situation;
+use crate::damage::DamageRules;
+use crate::events::GameEvent;
 use crate::fp_vec::FpVec;
-use crate::player::PlayerCard;
+use crate::phase::{self, Phase};
+use crate::player::{CardPlay, PlayedCard, PlayerCard};
+use crate::rng::GameRng;
+use crate::status::{self, Status, Tick};
 use crate::{
     enemy::Enemy, game_effects::GameEffect, player::Player, EffectTarget, EffectTrigger,
     EffectType, Enchantment,
 };
+use log::debug;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum GameOutcome {
     Undecided,
     PlayerWins(u32),
     EnemyWins(u32),
+    /// The last player and the last enemy fell together.
+    Draw(u32),
 }
 
-fn fold_effects((enemy, player): (Enemy, Player), effect: GameEffect) -> (Enemy, Player) {
-    match effect.target {
-        EffectTarget::Player => {
-            let player = player.trigger_effect(effect.effect, &enemy);
-            (enemy, player)
+impl GameOutcome {
+    pub fn description(&self) -> String {
+        match self {
+            GameOutcome::Undecided => "Undecided".to_string(),
+            GameOutcome::PlayerWins(turn) => format!("Player wins on turn {}", turn),
+            GameOutcome::EnemyWins(turn) => format!("Enemy wins on turn {}", turn),
+            GameOutcome::Draw(turn) => format!("Draw on turn {}", turn),
         }
-        EffectTarget::Enemy => {
-            let enemy = enemy.trigger_effect(effect.effect, &player);
-            (enemy, player)
+    }
+}
+
+/// Who wins when the last player and the last enemy fall together.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub enum TieRule {
+    /// Nobody does.
+    #[default]
+    Draw,
+    /// The side whose turn it is.
+    AttackerWins,
+}
+
+impl TieRule {
+    /// The outcome of both sides falling together in `phase`.
+    pub fn outcome(&self, phase: Phase, turn_number: u32) -> GameOutcome {
+        match self {
+            TieRule::Draw => GameOutcome::Draw(turn_number),
+            TieRule::AttackerWins if phase.players_turn() => GameOutcome::PlayerWins(turn_number),
+            TieRule::AttackerWins => GameOutcome::EnemyWins(turn_number),
+        }
+    }
+}
+
+type Resolution = (FpVec<Enemy>, FpVec<Player>, FpVec<GameEvent>);
+
+// Resolves `trigger` on the player or enemy at `target`.
+fn trigger_on(
+    (enemies, players, events): Resolution,
+    target: EffectTarget,
+    actor: usize,
+    trigger: EffectTrigger,
+    rules: &DamageRules,
+    turn_number: u32,
+    rng: &mut GameRng,
+) -> Resolution {
+    match target {
+        EffectTarget::EnemyAt(idx) => {
+            let (enemies, new_events) = combatant::update_at(enemies, idx, |enemy| {
+                enemy.trigger_effect(trigger, &players.inner[actor], rules, turn_number)
+            });
+            (enemies, players, events.extend(new_events))
+        }
+        EffectTarget::PlayerAt(idx) => {
+            let (players, new_events) = combatant::update_at(players, idx, |player| {
+                player.trigger_effect(trigger, &enemies, rules, turn_number, rng)
+            });
+            (enemies, players, events.extend(new_events))
+        }
+        _ => (enemies, players, events),
+    }
+}
+
+// `actor` is the player whose turn it is or who is attacked; unpinned effects go to them.
+fn fold_effects(
+    (enemies, players, events): Resolution,
+    effect: GameEffect,
+    actor: usize,
+    rules: &DamageRules,
+    turn_number: u32,
+    rng: &mut GameRng,
+) -> Resolution {
+    debug!("Resolving effect: {}", effect.description());
+    let targets = match effect.target {
+        EffectTarget::Player | EffectTarget::Ally => vec![EffectTarget::PlayerAt(actor)],
+        EffectTarget::AllPlayers => combatant::standing(&players)
+            .into_iter()
+            .map(EffectTarget::PlayerAt)
+            .collect(),
+        EffectTarget::Enemy => vec![EffectTarget::EnemyAt(combatant::first_standing(&enemies))],
+        EffectTarget::AllEnemies => combatant::standing(&enemies)
+            .into_iter()
+            .map(EffectTarget::EnemyAt)
+            .collect(),
+        target => vec![target],
+    };
+    targets
+        .into_iter()
+        .fold((enemies, players, events), |resolution, target| {
+            trigger_on(
+                resolution,
+                target,
+                actor,
+                effect.effect.clone(),
+                rules,
+                turn_number,
+                rng,
+            )
+        })
+}
+
+// Whether a side has fallen.
+fn decided((enemies, players, _): &Resolution) -> bool {
+    combatant::standing(enemies).is_empty() || combatant::standing(players).is_empty()
+}
+
+// Resolves `effects` in order until a side falls.
+fn resolve_effects(
+    resolution: Resolution,
+    effects: FpVec<GameEffect>,
+    actor: usize,
+    rules: &DamageRules,
+    turn_number: u32,
+    rng: &mut GameRng,
+) -> Resolution {
+    effects
+        .inner
+        .into_iter()
+        .fold(resolution, |resolution, effect| {
+            if decided(&resolution) {
+                resolution
+            } else {
+                fold_effects(resolution, effect, actor, rules, turn_number, rng)
+            }
+        })
+}
+
+// The card's effects stop once a side falls; counters still hit whoever's standing.
+fn resolve_played(
+    resolution: Resolution,
+    card: PlayedCard,
+    me: usize,
+    rules: &DamageRules,
+    turn_number: u32,
+    rng: &mut GameRng,
+) -> Resolution {
+    if decided(&resolution) {
+        return resolution;
+    }
+    let resolution = resolve_effects(resolution, card.effects, me, rules, turn_number, rng);
+    card.counters
+        .inner
+        .into_iter()
+        .fold(resolution, |resolution, effect| {
+            let standing = match &effect.target {
+                EffectTarget::EnemyAt(idx) => resolution
+                    .0
+                    .inner
+                    .get(*idx)
+                    .is_some_and(|enemy| enemy.is_standing()),
+                EffectTarget::PlayerAt(idx) => resolution
+                    .1
+                    .inner
+                    .get(*idx)
+                    .is_some_and(|player| player.is_standing()),
+                _ => true,
+            };
+            if standing {
+                fold_effects(resolution, effect, me, rules, turn_number, rng)
+            } else {
+                resolution
+            }
+        })
+}
+
+// Ticks the statuses of the player or enemy at `target`, dropping those that wear off.
+fn tick_statuses(
+    (enemies, players, events): Resolution,
+    target: EffectTarget,
+    tick: Tick,
+    actor: usize,
+    rules: &DamageRules,
+    turn_number: u32,
+    rng: &mut GameRng,
+) -> Resolution {
+    let (enemies, players, effects, expired) = match target {
+        EffectTarget::EnemyAt(idx) => {
+            let (kept, effects, expired) = status::tick(enemies.inner[idx].statuses.clone(), tick);
+            let (enemies, expired) =
+                combatant::update_at(enemies, idx, |enemy| keep_statuses(enemy, kept, expired));
+            (enemies, players, effects, expired)
+        }
+        EffectTarget::PlayerAt(idx) => {
+            let (kept, effects, expired) = status::tick(players.inner[idx].statuses.clone(), tick);
+            let (players, expired) =
+                combatant::update_at(players, idx, |player| keep_statuses(player, kept, expired));
+            (enemies, players, effects, expired)
+        }
+        _ => (enemies, players, FpVec::new(), FpVec::new()),
+    };
+    let effects = effects
+        .inner
+        .into_iter()
+        .map(|(name, effect)| GameEffect {
+            name,
+            target: target.clone(),
+            effect: EffectTrigger::Always(effect),
+        })
+        .collect();
+    resolve_effects(
+        (enemies, players, events.extend(expired)),
+        FpVec::from_vec(effects),
+        actor,
+        rules,
+        turn_number,
+        rng,
+    )
+}
+
+fn keep_statuses<C: Combatant>(
+    combatant: C,
+    statuses: FpVec<Status>,
+    expired: FpVec<Status>,
+) -> (C, FpVec<GameEvent>) {
+    let vitals = combatant.vitals();
+    let events = expired
+        .inner
+        .into_iter()
+        .map(|status| GameEvent::StatusExpired {
+            target: C::TARGET,
+            status,
+        })
+        .collect();
+    (
+        combatant.with_vitals(Vitals { statuses, ..vitals }),
+        FpVec::from_vec(events),
+    )
+}
+
+// What the enemies still standing do to player `idx`, picked out by `hooks`.
+fn player_turn_hooks(
+    enemies: &FpVec<Enemy>,
+    idx: usize,
+    hooks: impl Fn(&Enemy) -> FpVec<GameEffect>,
+) -> FpVec<GameEffect> {
+    FpVec::from_vec(
+        enemies
+            .inner
+            .iter()
+            .enumerate()
+            .filter(|(_, enemy)| enemy.is_standing())
+            .flat_map(|(enemy_idx, enemy)| {
+                hooks(enemy)
+                    .inner
+                    .into_iter()
+                    .map(move |eff| eff.aimed_at(enemy_idx).aimed_at_player(idx, idx))
+            })
+            .collect(),
+    )
+}
+
+// The `phase` hooks of the enemies still standing and of player `idx`'s hand.
+fn player_phase_hooks(
+    (enemies, players, _): &Resolution,
+    idx: usize,
+    phase: Phase,
+) -> FpVec<GameEffect> {
+    player_turn_hooks(enemies, idx, |enemy| {
+        phase::effects_for(&enemy.phase_effects, phase)
+    })
+    .extend(card_hooks(&players.inner[idx].hand, idx, phase, None))
+}
+
+// The `phase` hooks of `cards` in player `idx`'s hand, aimed at `enemy` if given.
+fn card_hooks(
+    cards: &FpVec<PlayerCard>,
+    idx: usize,
+    phase: Phase,
+    enemy: Option<usize>,
+) -> FpVec<GameEffect> {
+    FpVec::from_vec(
+        cards
+            .inner
+            .iter()
+            .flat_map(|card| phase::effects_for(&card.phase_effects, phase).inner)
+            .map(|eff| {
+                let eff = eff.aimed_at_player(idx, idx);
+                match enemy {
+                    Some(enemy) => eff.aimed_at(enemy),
+                    None => eff,
+                }
+            })
+            .collect(),
+    )
+}
+
+// Hooks for a shared phase: the enemies' go to the first player standing, cards to their owner.
+fn shared_phase(
+    resolution: Resolution,
+    phase: Phase,
+    rules: &DamageRules,
+    turn_number: u32,
+    rng: &mut GameRng,
+) -> Resolution {
+    let first = combatant::first_standing(&resolution.1);
+    let effects = player_turn_hooks(&resolution.0, first, |enemy| {
+        phase::effects_for(&enemy.phase_effects, phase)
+    });
+    let resolution = resolve_effects(resolution, effects, first, rules, turn_number, rng);
+    (0..resolution.1.inner.len()).fold(resolution, |resolution, idx| {
+        if resolution.1.inner[idx].is_standing() {
+            let effects = card_hooks(&resolution.1.inner[idx].hand, idx, phase, None);
+            resolve_effects(resolution, effects, idx, rules, turn_number, rng)
+        } else {
+            resolution
+        }
+    })
+}
+
+// Start-of-turn effects, the draw included, resolve before any cards are chosen.
+fn begin_player_turn(
+    resolution: Resolution,
+    idx: usize,
+    rules: &DamageRules,
+    turn_number: u32,
+    rng: &mut GameRng,
+) -> Resolution {
+    let (enemies, players, events) = tick_statuses(
+        resolution,
+        EffectTarget::PlayerAt(idx),
+        Tick::OnStart,
+        idx,
+        rules,
+        turn_number,
+        rng,
+    );
+    let (players, _) =
+        combatant::update_at(players, idx, |player| (player.begin_turn(), FpVec::new()));
+    let resolution = (enemies, players, events);
+    let start_turn = resolution.1.inner[idx].start_turn();
+    let effects = FpVec::from_vec(
+        start_turn
+            .inner
+            .into_iter()
+            .map(|effect| effect.aimed_at_player(idx, idx))
+            .collect(),
+    )
+    .extend(player_turn_hooks(&resolution.0, idx, |enemy| {
+        enemy.player_start_turn_effects.clone()
+    }))
+    .extend(player_phase_hooks(&resolution, idx, Phase::PlayerUpkeep));
+    resolve_effects(resolution, effects, idx, rules, turn_number, rng)
+}
+
+fn begin_round(
+    (enemies, players, events): Resolution,
+    rules: &DamageRules,
+    turn_number: u32,
+    rng: &mut GameRng,
+) -> Resolution {
+    let enemies = FpVec::from_vec(enemies.inner.into_iter().map(Enemy::begin_round).collect());
+    (0..players.inner.len()).fold((enemies, players, events), |resolution, idx| {
+        if resolution.1.inner[idx].is_standing() && !decided(&resolution) {
+            begin_player_turn(resolution, idx, rules, turn_number, rng)
+        } else {
+            resolution
         }
+    })
+}
+
+// The hooks for `phase` of enemy `idx` and of the cards in its victim's hand.
+fn enemy_phase_hooks(
+    (enemies, players, _): &Resolution,
+    idx: usize,
+    victim: usize,
+    phase: Phase,
+) -> FpVec<GameEffect> {
+    FpVec::from_vec(
+        phase::effects_for(&enemies.inner[idx].phase_effects, phase)
+            .inner
+            .into_iter()
+            .map(|effect| effect.aimed_at(idx).aimed_at_player(victim, victim))
+            .collect(),
+    )
+    .extend(card_hooks(
+        &players.inner[victim].hand,
+        victim,
+        phase,
+        Some(idx),
+    ))
+}
+
+// One enemy's upkeep, action and end phases; also returns the phase a side fell in.
+fn take_single_enemy_turn(
+    resolution: Resolution,
+    idx: usize,
+    rules: &DamageRules,
+    turn_number: u32,
+    rng: &mut GameRng,
+) -> (Resolution, Phase) {
+    let victim = resolution.0.inner[idx].targeting.pick(&resolution.1, rng);
+    let resolution = tick_statuses(
+        resolution,
+        EffectTarget::EnemyAt(idx),
+        Tick::OnStart,
+        victim,
+        rules,
+        turn_number,
+        rng,
+    );
+    let effects = enemy_phase_hooks(&resolution, idx, victim, Phase::EnemyUpkeep);
+    let resolution = resolve_effects(resolution, effects, victim, rules, turn_number, rng);
+    if decided(&resolution) {
+        return (resolution, Phase::EnemyUpkeep);
     }
+    let enemy = resolution.0.inner[idx].clone();
+    let resolution = if !enemy.is_standing() {
+        resolution
+    } else if !status::is_stunned(&enemy.statuses) {
+        enemy_action(resolution, idx, victim, rules, turn_number, rng)
+    } else {
+        let (enemies, players, events) = resolution;
+        let (enemies, _) = combatant::update_at(enemies, idx, |enemy| {
+            let enemy = Enemy {
+                skipped_last_turn: true,
+                ..enemy
+            };
+            (enemy, FpVec::new())
+        });
+        (
+            enemies,
+            players,
+            events.push(GameEvent::TurnSkipped {
+                target: EffectTarget::EnemyAt(idx),
+            }),
+        )
+    };
+    if decided(&resolution) {
+        return (resolution, Phase::EnemyAction);
+    }
+    let effects = enemy_phase_hooks(&resolution, idx, victim, Phase::EnemyEnd);
+    let resolution = resolve_effects(resolution, effects, victim, rules, turn_number, rng);
+    let resolution = tick_statuses(
+        resolution,
+        EffectTarget::EnemyAt(idx),
+        Tick::OnEnd,
+        victim,
+        rules,
+        turn_number,
+        rng,
+    );
+    (resolution, Phase::EnemyEnd)
+}
+
+// Enemy `idx` acts on `victim`: start effects, intended move, end effects, then summons.
+fn enemy_action(
+    resolution: Resolution,
+    idx: usize,
+    victim: usize,
+    rules: &DamageRules,
+    turn_number: u32,
+    rng: &mut GameRng,
+) -> Resolution {
+    let enemy = resolution.0.inner[idx].clone();
+    let player = &resolution.1.inner[victim];
+    let effects =
+        enemy_phase_hooks(&resolution, idx, victim, Phase::EnemyAction).extend(FpVec::from_vec(
+            enemy
+                .start_turn(player)
+                .extend(enemy.end_turn(player))
+                .inner
+                .into_iter()
+                .map(|effect| effect.aimed_at(idx).aimed_at_player(victim, victim))
+                .collect(),
+        ));
+    let (enemies, players, events) = resolution;
+    let events = match enemy.intended_move() {
+        Some(intent) => events.push(GameEvent::MoveMade {
+            target: EffectTarget::EnemyAt(idx),
+            name: intent.name.clone(),
+        }),
+        None => events,
+    };
+    let resolution = resolve_effects(
+        (enemies, players, events),
+        effects,
+        victim,
+        rules,
+        turn_number,
+        rng,
+    );
+    let summons = if decided(&resolution) {
+        FpVec::new()
+    } else {
+        enemy
+            .intended_move()
+            .map_or(FpVec::new(), |intent| intent.summons.clone())
+    };
+    let (enemies, players, events) =
+        summons
+            .inner
+            .into_iter()
+            .fold(resolution, |(enemies, players, events), summoned| {
+                let event = GameEvent::EnemySummoned {
+                    target: EffectTarget::EnemyAt(enemies.inner.len()),
+                    name: summoned.name.clone(),
+                };
+                (
+                    enemies.push(summoned.choose_intent(rng)),
+                    players,
+                    events.push(event),
+                )
+            });
+    // The enemy has made its move, so it picks the next one.
+    let (enemies, _) = combatant::update_at(enemies, idx, |enemy| {
+        let enemy = Enemy {
+            skipped_last_turn: false,
+            ..enemy
+        };
+        (enemy.choose_intent(rng), FpVec::new())
+    });
+    (enemies, players, events)
 }
 
-#[derive(Debug)]
+// Player `me` plays `plays` in order, each against the board the cards before it left.
+fn play_cards(
+    resolution: Resolution,
+    me: usize,
+    plays: FpVec<CardPlay>,
+    rules: &DamageRules,
+    turn_number: u32,
+    rng: &mut GameRng,
+) -> Resolution {
+    let (resolution, _) = plays.inner.into_iter().fold(
+        (resolution, FpVec::new()),
+        |(resolution, played): (Resolution, FpVec<String>), play| {
+            if decided(&resolution) {
+                return (resolution, played);
+            }
+            let id = play.card.id.clone();
+            let (enemies, players, events) = resolution;
+            let (player, card, card_events) =
+                players.inner[me].play_card(me, &enemies, &players, play, &played, turn_number);
+            let (players, _) = combatant::update_at(players, me, |_| (player, FpVec::new()));
+            let resolution = (enemies, players, events.extend(card_events));
+            match card {
+                Some(card) => (
+                    resolve_played(resolution, card, me, rules, turn_number, rng),
+                    played.push(id),
+                ),
+                None => (resolution, played),
+            }
+        },
+    );
+    resolution
+}
+
+fn game_over_events(game_result: &GameOutcome) -> FpVec<GameEvent> {
+    match game_result {
+        GameOutcome::Undecided => FpVec::new(),
+        outcome => FpVec::from_vec(vec![GameEvent::GameOver {
+            outcome: outcome.clone(),
+        }]),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Game {
-    pub enemy: Enemy,
-    pub player: Player,
+    pub enemies: FpVec<Enemy>,
+    /// Everyone playing, in turn order.
+    pub players: FpVec<Player>,
+    /// The player whose turn it is.
+    pub current_player: usize,
+    /// The phase the game waits in: a player's main phase or the enemies' upkeep.
+    pub phase: Phase,
     pub turn_number: u32,
     pub game_result: GameOutcome,
+    /// The seed the game was started with.
+    #[serde(with = "crate::rng::as_i64")]
+    pub seed: u64,
+    pub rng: GameRng,
+    /// How attacks turn into damage.
+    #[serde(default)]
+    pub damage_rules: DamageRules,
+    #[serde(default)]
+    pub tie_rule: TieRule,
+    /// What happened in the transition that produced this state.
+    #[serde(default)]
+    pub events: FpVec<GameEvent>,
 }
 
 impl Game {
-    pub fn check_game_result(enemy: &Enemy, player: &Player, turn_number: u32) -> GameOutcome {
+    /// Who has won, with `tie_rule` deciding when both sides are down in `phase`.
+    pub fn check_game_result(
+        enemies: &FpVec<Enemy>,
+        players: &FpVec<Player>,
+        turn_number: u32,
+        phase: Phase,
+        tie_rule: &TieRule,
+    ) -> GameOutcome {
+        let players_down = combatant::standing(players).is_empty();
+        let enemies_down = combatant::standing(enemies).is_empty();
         match 0 {
-            _ if player.hit_points <= 0 => GameOutcome::EnemyWins(turn_number),
-            _ if enemy.hit_points <= 0 => GameOutcome::PlayerWins(turn_number),
+            _ if players_down && enemies_down => tie_rule.outcome(phase, turn_number),
+            _ if players_down => GameOutcome::EnemyWins(turn_number),
+            _ if enemies_down => GameOutcome::PlayerWins(turn_number),
             _ => GameOutcome::Undecided,
         }
     }
 
+    /// Decides simultaneous defeats by `tie_rule` from now on.
+    pub fn tie_rule(self, tie_rule: TieRule) -> Self {
+        Self { tie_rule, ..self }
+    }
+
+    /// The player whose turn it is.
+    pub fn player(&self) -> &Player {
+        &self.players.inner[self.current_player]
+    }
+
+    /// The cards the current player could play now, or none if the game isn't waiting on them.
+    pub fn legal_plays(&self) -> FpVec<PlayerCard> {
+        let player = self.player();
+        if !self.phase.awaits_player()
+            || self.game_result != GameOutcome::Undecided
+            || status::is_stunned(&player.statuses)
+        {
+            return FpVec::new();
+        }
+        FpVec::from_vec(
+            player
+                .hand
+                .inner
+                .iter()
+                .filter(|card| {
+                    let card = player.enchant_card((*card).clone());
+                    card.can_play && player.card_cost(&card) <= player.power
+                })
+                .cloned()
+                .collect(),
+        )
+    }
+
+    /// The enemies cards can be aimed at now.
+    pub fn legal_targets(&self) -> Vec<usize> {
+        match 0 {
+            _ if !self.phase.awaits_player() => vec![],
+            _ if self.game_result != GameOutcome::Undecided => vec![],
+            _ => combatant::standing(&self.enemies),
+        }
+    }
+
+    /// Hit points left across all enemies still standing.
+    pub fn enemy_hit_points(&self) -> i32 {
+        self.enemies
+            .inner
+            .iter()
+            .filter(|enemy| enemy.is_standing())
+            .map(|enemy| enemy.hit_points)
+            .sum()
+    }
+
+    /// Hit points left across all players still standing.
+    pub fn player_hit_points(&self) -> i32 {
+        self.players
+            .inner
+            .iter()
+            .filter(|player| player.is_standing())
+            .map(|player| player.hit_points)
+            .sum()
+    }
+
+    /// The enchantments `enchantments` grant the enemy and the player as the game starts.
     pub fn check_enchantments(
         enchantments: &FpVec<GameEffect>,
         enemy: &Enemy,
         player: &Player,
     ) -> (FpVec<Enchantment>, FpVec<Enchantment>) {
+        let situation = Situation::new(player, enemy, 1);
         enchantments.inner.iter().fold(
             (FpVec::new(), FpVec::new()),
             |(enemy_vec, player_vec), eff| {
@@ -56,7 +699,7 @@ impl Game {
                             (enemy_vec.push(ench.clone()), player_vec)
                         }
                         EffectTrigger::Condition(cond, EffectType::Enchantment(ench))
-                            if cond.check_enemy(&enemy) =>
+                            if cond.check(&situation) =>
                         {
                             (enemy_vec.push(ench.clone()), player_vec)
                         }
@@ -68,7 +711,7 @@ impl Game {
                             (enemy_vec, player_vec.push(ench.clone()))
                         }
                         EffectTrigger::Condition(cond, EffectType::Enchantment(ench))
-                            if cond.check_player(&player) =>
+                            if cond.check(&situation) =>
                         {
                             (enemy_vec, player_vec.push(ench.clone()))
                         }
@@ -79,115 +722,342 @@ impl Game {
         )
     }
 
+    /// Starts a game with a random seed.
     pub fn start(enemy: Enemy, player: Player) -> Self {
-        let (enemy_ench_from_enemy, player_ench_from_enemy) =
-            Self::check_enchantments(&enemy.enchantments, &enemy, &player);
-        let (enemy_ench_from_player, player_ench_from_player) = player.cards.inner.iter().fold(
+        Self::start_seeded(enemy, player, rand::random())
+    }
+
+    pub fn start_seeded(enemy: Enemy, player: Player, seed: u64) -> Self {
+        Self::start_encounter(FpVec::from_vec(vec![enemy]), player, seed)
+    }
+
+    pub fn start_encounter(enemies: FpVec<Enemy>, player: Player, seed: u64) -> Self {
+        Self::start_coop(enemies, FpVec::from_vec(vec![player]), seed)
+    }
+
+    /// Starts `players` against `enemies`, with every random choice drawn from `seed`.
+    pub fn start_coop(enemies: FpVec<Enemy>, players: FpVec<Player>, seed: u64) -> Self {
+        Self::start_with_rules(enemies, players, seed, DamageRules::default())
+    }
+
+    /// Starts a game like `start_coop` whose damage follows `damage_rules`.
+    pub fn start_with_rules(
+        enemies: FpVec<Enemy>,
+        players: FpVec<Player>,
+        seed: u64,
+        damage_rules: DamageRules,
+    ) -> Self {
+        let mut rng = GameRng::new(seed);
+        let (players, draw_events) = players.inner.into_iter().fold(
             (FpVec::new(), FpVec::new()),
-            |(enemy_eff_vec, player_eff_vec), card| {
-                let (e_vec, p_vec) =
-                    Self::check_enchantments(&card.game_start_effects, &enemy, &player);
-                (enemy_eff_vec.extend(e_vec), player_eff_vec.extend(p_vec))
+            |(players, events), player| {
+                let player = player.assign_card_ids(&mut rng);
+                let (player, draw_events) = player.draw_starting_hand(&mut rng);
+                (players.push(player), events.extend(draw_events))
             },
         );
 
-        let enemy_enchantments = enemy_ench_from_enemy.extend(enemy_ench_from_player);
-        let player_enchantments = player_ench_from_enemy.extend(player_ench_from_player);
-        println!(
-            "Game Starting with global enchantments:\n  * Enemy [{}]\n  * Player [{}]",
-            enemy_enchantments
+        // Enemies' player enchantments reach every player; cards' enemy ones reach every enemy.
+        let enemy_enchantments: Vec<FpVec<Enchantment>> = enemies
+            .inner
+            .iter()
+            .map(|enemy| {
+                let own = players.inner.first().map_or(FpVec::new(), |player| {
+                    Self::check_enchantments(&enemy.enchantments, enemy, player).0
+                });
+                players.inner.iter().fold(own, |enemy_eff_vec, player| {
+                    player
+                        .hand
+                        .inner
+                        .iter()
+                        .fold(enemy_eff_vec, |enemy_eff_vec, card| {
+                            let (e_vec, _) =
+                                Self::check_enchantments(&card.game_start_effects, enemy, player);
+                            enemy_eff_vec.extend(e_vec)
+                        })
+                })
+            })
+            .collect();
+        let player_enchantments: Vec<FpVec<Enchantment>> = players
+            .inner
+            .iter()
+            .map(|player| {
+                let from_enemies = enemies.inner.iter().fold(FpVec::new(), |p_vec, enemy| {
+                    p_vec.extend(Self::check_enchantments(&enemy.enchantments, enemy, player).1)
+                });
+                enemies.inner.first().map_or(from_enemies.clone(), |enemy| {
+                    player.hand.inner.iter().fold(from_enemies, |p_vec, card| {
+                        let (_, p) =
+                            Self::check_enchantments(&card.game_start_effects, enemy, player);
+                        p_vec.extend(p)
+                    })
+                })
+            })
+            .collect();
+
+        let enchantment_events =
+            FpVec::from_vec(
+                enemy_enchantments
+                    .iter()
+                    .enumerate()
+                    .flat_map(|(idx, enchantments)| {
+                        enchantments
+                            .inner
+                            .iter()
+                            .map(move |ench| (EffectTarget::EnemyAt(idx), ench))
+                    })
+                    .chain(player_enchantments.iter().enumerate().flat_map(
+                        |(idx, enchantments)| {
+                            enchantments
+                                .inner
+                                .iter()
+                                .map(move |ench| (EffectTarget::PlayerAt(idx), ench))
+                        },
+                    ))
+                    .map(|(target, ench)| GameEvent::EnchantmentActivated {
+                        target,
+                        enchantment: ench.clone(),
+                    })
+                    .collect(),
+            );
+
+        let enemies = FpVec::from_vec(
+            enemies
                 .inner
-                .iter()
-                .fold(String::new(), |desc, ench| format!(
-                    "{}{}",
-                    desc,
-                    ench.description()
-                )),
-            player_enchantments
+                .into_iter()
+                .zip(enemy_enchantments)
+                .map(|(enemy, current_activated_effects)| {
+                    Enemy {
+                        current_activated_effects,
+                        ..enemy
+                    }
+                    .choose_intent(&mut rng)
+                })
+                .collect(),
+        );
+        let players = FpVec::from_vec(
+            players
                 .inner
-                .iter()
-                .fold(String::new(), |desc, ench| format!(
-                    "{}{}",
-                    desc,
-                    ench.description()
-                )),
+                .into_iter()
+                .zip(player_enchantments)
+                .map(|(player, current_activated_effects)| Player {
+                    current_activated_effects,
+                    ..player
+                })
+                .collect(),
+        );
+        let resolution = shared_phase(
+            (enemies, players, draw_events.extend(enchantment_events)),
+            Phase::GameStart,
+            &damage_rules,
+            1,
+            &mut rng,
         );
+        let (enemies, players, events) = begin_round(resolution, &damage_rules, 1, &mut rng);
+        let tie_rule = TieRule::default();
+        let game_result =
+            Self::check_game_result(&enemies, &players, 1, Phase::PlayerUpkeep, &tie_rule);
 
         Game {
-            enemy: Enemy {
-                current_activated_effects: enemy_enchantments,
-                ..enemy
-            },
-            player: Player {
-                current_activated_effects: player_enchantments,
-                ..player
-            },
+            current_player: combatant::first_standing(&players),
+            phase: Phase::PlayerMain,
+            enemies,
+            players,
             turn_number: 1,
-            game_result: GameOutcome::Undecided,
+            events: events.extend(game_over_events(&game_result)),
+            game_result,
+            seed,
+            rng,
+            damage_rules,
+            tie_rule,
         }
     }
 
-    pub fn take_player_turn(self, card_play_list: FpVec<PlayerCard>) -> Self {
-        let enemy = self.enemy;
-        let player = self.player;
-
-        let start_effects = player.start_turn();
-        let play_effects = card_play_list
+    // The next player still standing after the current one this round.
+    fn next_player(&self) -> Option<usize> {
+        self.players
             .inner
-            .into_iter()
-            .fold(FpVec::new(), |effects, card| {
-                effects.extend(player.player_play_card(&enemy, card))
-            });
-        let effects = start_effects.extend(play_effects).extend(player.end_turn());
+            .iter()
+            .enumerate()
+            .skip(self.current_player + 1)
+            .find(|(_, player)| player.is_standing())
+            .map(|(idx, _)| idx)
+    }
 
-        let (enemy, player) = effects
-            .inner
-            .into_iter()
-            .fold((enemy, player), fold_effects);
+    /// The current player's main and end phases; nothing happens outside their main phase.
+    pub fn take_player_turn(self, card_play_list: FpVec<CardPlay>) -> Self {
+        if !self.phase.awaits_player() || self.game_result != GameOutcome::Undecided {
+            return Self {
+                events: FpVec::new(),
+                ..self
+            };
+        }
+        let me = self.current_player;
+        let mut rng = self.rng.clone();
+        let rules = &self.damage_rules;
 
-        let game_result = Self::check_game_result(&enemy, &player, self.turn_number);
+        let resolution = (self.enemies.clone(), self.players.clone(), FpVec::new());
+        let effects = player_phase_hooks(&resolution, me, Phase::PlayerMain);
+        let resolution =
+            resolve_effects(resolution, effects, me, rules, self.turn_number, &mut rng);
 
-        let player = Player { ..player };
-        Self {
-            enemy,
-            player,
+        // A stunned player loses the chance to play cards.
+        let resolution = match 0 {
+            _ if decided(&resolution) => resolution,
+            _ if status::is_stunned(&resolution.1.inner[me].statuses) => {
+                let (enemies, players, events) = resolution;
+                let event = GameEvent::TurnSkipped {
+                    target: EffectTarget::PlayerAt(me),
+                };
+                (enemies, players, events.push(event))
+            }
+            _ => play_cards(
+                resolution,
+                me,
+                card_play_list,
+                rules,
+                self.turn_number,
+                &mut rng,
+            ),
+        };
+
+        let end_turn = resolution.1.inner[me].end_turn();
+        let effects = FpVec::from_vec(
+            end_turn
+                .inner
+                .into_iter()
+                .map(|effect| effect.aimed_at_player(me, me))
+                .collect(),
+        )
+        .extend(player_turn_hooks(&resolution.0, me, |enemy| {
+            enemy.player_end_turn_effects.clone()
+        }))
+        .extend(player_phase_hooks(&resolution, me, Phase::PlayerEnd));
+        let resolution =
+            resolve_effects(resolution, effects, me, rules, self.turn_number, &mut rng);
+        let (enemies, players, events) = tick_statuses(
+            resolution,
+            EffectTarget::PlayerAt(me),
+            Tick::OnEnd,
+            me,
+            rules,
+            self.turn_number,
+            &mut rng,
+        );
+
+        let game_result = Self::check_game_result(
+            &enemies,
+            &players,
+            self.turn_number,
+            Phase::PlayerEnd,
+            &self.tie_rule,
+        );
+        let game = Self {
+            enemies,
+            players,
+            events: events.extend(game_over_events(&game_result)),
             game_result,
+            rng,
             ..self
+        };
+        match game.next_player() {
+            Some(next) => Self {
+                current_player: next,
+                ..game
+            },
+            None => Self {
+                phase: Phase::EnemyUpkeep,
+                ..game
+            },
         }
     }
 
-    pub fn take_enemy_turn(self) -> Self {
-        let enemy = self.enemy;
-        let player = self.player;
+    /// The current player's turn, then the enemies' once everyone has played, keeping all events.
+    pub fn take_turn(self, card_play_list: FpVec<CardPlay>) -> Self {
+        let game = self.take_player_turn(card_play_list);
+        match game.game_result {
+            GameOutcome::Undecided if game.phase.awaits_enemies() => {
+                let player_events = game.events.clone();
+                let game = game.take_enemy_turn();
+                Self {
+                    events: player_events.extend(game.events),
+                    ..game
+                }
+            }
+            _ => game,
+        }
+    }
 
-        let (enemy, player) = enemy
-            .temp_start_turn_effects
-            .clone()
-            .inner
-            .into_iter()
-            .fold((enemy, player), fold_effects);
-        let (enemy, player) = if !enemy.skip_next_turn {
-            let effects = enemy.start_turn(&player).extend(enemy.end_turn(&player));
+    /// The enemies' turns and the round's cleanup; nothing happens unless the game waits on them.
+    pub fn take_enemy_turn(self) -> Self {
+        if !self.phase.awaits_enemies() || self.game_result != GameOutcome::Undecided {
+            return Self {
+                events: FpVec::new(),
+                ..self
+            };
+        }
+        let mut rng = self.rng;
+        let rules = self.damage_rules.clone();
 
-            effects
-                .inner
-                .into_iter()
-                .fold((enemy, player), fold_effects)
+        let (resolution, phase) = (0..self.enemies.inner.len()).fold(
+            (
+                (self.enemies, self.players, FpVec::new()),
+                Phase::EnemyUpkeep,
+            ),
+            |(resolution, phase), idx| {
+                if resolution.0.inner[idx].is_standing() && !decided(&resolution) {
+                    take_single_enemy_turn(resolution, idx, &rules, self.turn_number, &mut rng)
+                } else {
+                    (resolution, phase)
+                }
+            },
+        );
+        let ((enemies, players, events), phase) = if decided(&resolution) {
+            (resolution, phase)
         } else {
-            (enemy, player)
+            let resolution = shared_phase(
+                resolution,
+                Phase::Cleanup,
+                &rules,
+                self.turn_number,
+                &mut rng,
+            );
+            (resolution, Phase::Cleanup)
         };
 
-        let game_result = Self::check_game_result(&enemy, &player, self.turn_number);
+        let game_result =
+            Self::check_game_result(&enemies, &players, self.turn_number, phase, &self.tie_rule);
+        let (enemies, players, events) = if game_result == GameOutcome::Undecided {
+            begin_round(
+                (enemies, players, events),
+                &rules,
+                self.turn_number + 1,
+                &mut rng,
+            )
+        } else {
+            (enemies, players, events)
+        };
+        let turn_number = self.turn_number + 1;
+        let game_result = match game_result {
+            GameOutcome::Undecided => Self::check_game_result(
+                &enemies,
+                &players,
+                turn_number,
+                Phase::PlayerUpkeep,
+                &self.tie_rule,
+            ),
+            decided => decided,
+        };
 
         Self {
-            enemy: Enemy {
-                skip_next_turn: false,
-                temp_start_turn_effects: FpVec::new(),
-                ..enemy
-            },
-            player,
+            current_player: combatant::first_standing(&players),
+            phase: Phase::PlayerMain,
+            enemies,
+            players,
+            events: events.extend(game_over_events(&game_result)),
             game_result,
-            turn_number: self.turn_number + 1,
+            turn_number,
+            rng,
             ..self
         }
     }
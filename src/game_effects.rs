@@ -1,8 +1,11 @@
+use crate::status::Status;
 use crate::{
-    Damage, EffectCondition, EffectTarget, EffectTrigger, EffectType, ElementType, Enchantment,
+    CardZone, Damage, DamageAdjustment, EffectCondition, EffectTarget, EffectTrigger, EffectType,
+    ElementType, Enchantment, PowerCostAdjust,
 };
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct GameEffect {
     pub name: String,
     pub target: EffectTarget,
@@ -33,6 +36,62 @@ impl GameEffect {
             effect,
         }
     }
+
+    pub fn ally(name: &str, effect: EffectTrigger) -> Self {
+        Self {
+            name: name.to_string(),
+            target: EffectTarget::Ally,
+            effect,
+        }
+    }
+
+    pub fn all_players(name: &str, effect: EffectTrigger) -> Self {
+        Self {
+            name: name.to_string(),
+            target: EffectTarget::AllPlayers,
+            effect,
+        }
+    }
+
+    pub fn all_enemies(name: &str, effect: EffectTrigger) -> Self {
+        Self {
+            name: name.to_string(),
+            target: EffectTarget::AllEnemies,
+            effect,
+        }
+    }
+
+    /// Adds `bonus` to the damage the effect deals, if it deals any.
+    pub fn damage_bonus(self, bonus: i32) -> Self {
+        let add = |dmg: Damage| Damage {
+            bonus: dmg.bonus + bonus,
+            ..dmg
+        };
+        let effect = match self.effect {
+            EffectTrigger::Always(EffectType::Damage(dmg)) => {
+                EffectTrigger::Always(EffectType::Damage(add(dmg)))
+            }
+            EffectTrigger::Condition(cond, EffectType::Damage(dmg)) => {
+                EffectTrigger::Condition(cond, EffectType::Damage(add(dmg)))
+            }
+            effect => effect,
+        };
+        Self { effect, ..self }
+    }
+
+    pub fn aimed_at(self, enemy: usize) -> Self {
+        Self {
+            target: self.target.aimed_at(enemy),
+            ..self
+        }
+    }
+
+    pub fn aimed_at_player(self, player: usize, ally: usize) -> Self {
+        Self {
+            target: self.target.aimed_at_player(player, ally),
+            ..self
+        }
+    }
 }
 
 pub struct EnemyEffects;
@@ -40,10 +99,76 @@ impl EnemyEffects {
     pub fn attack(amount: i32) -> GameEffect {
         GameEffect::player(
             "Enemy Attack",
-            EffectTrigger::Always(EffectType::Damage(Damage {
-                element_type: ElementType::NoElement,
+            EffectTrigger::Always(EffectType::Damage(Damage::new(
+                ElementType::NoElement,
                 amount,
-            })),
+            ))),
+        )
+    }
+
+    pub fn elemental_attack(element_type: ElementType, amount: i32) -> GameEffect {
+        GameEffect::player(
+            &format!("Enemy {} Attack", element_type.description()),
+            EffectTrigger::Always(EffectType::Damage(Damage::new(element_type, amount))),
+        )
+    }
+
+    /// An extra attack once the enemy is below `percent` of its hit points.
+    pub fn enraged_attack(percent: f64, amount: i32) -> GameEffect {
+        GameEffect::player(
+            "Enraged Attack",
+            EffectTrigger::Condition(
+                EffectCondition::EnemyHitPointsBelowPercent(percent),
+                EffectType::Damage(Damage::new(ElementType::NoElement, amount)),
+            ),
+        )
+    }
+
+    /// Strikes back when the enemy took damage of the element this round.
+    pub fn retaliate(element_type: ElementType, amount: i32) -> GameEffect {
+        GameEffect::player(
+            &format!("Retaliate against {}", element_type.description()),
+            EffectTrigger::Condition(
+                EffectCondition::EnemyTookElementDamage(element_type),
+                EffectType::Damage(Damage::new(ElementType::NoElement, amount)),
+            ),
+        )
+    }
+
+    pub fn afflict_player(status: Status) -> GameEffect {
+        GameEffect::player(
+            &format!("{} to Player", status.kind.description()),
+            EffectTrigger::Always(EffectType::Status(status)),
+        )
+    }
+
+    pub fn percent_attack(pct: f64) -> GameEffect {
+        GameEffect::player(
+            "Enemy Percent Attack",
+            EffectTrigger::Always(EffectType::PercentDamage(pct)),
+        )
+    }
+
+    pub fn skip_player_turn() -> GameEffect {
+        GameEffect::player("Skip Turn", EffectTrigger::Always(EffectType::SkipTurn))
+    }
+
+    /// Takes `amount` hit points from the player, past any defenses.
+    pub fn drain_player(amount: i32) -> GameEffect {
+        GameEffect::player(
+            &format!("Drain {}", amount),
+            EffectTrigger::Always(EffectType::LifeAdjust(-amount)),
+        )
+    }
+
+    pub fn dispel_player() -> GameEffect {
+        GameEffect::player("Dispel Player", EffectTrigger::Always(EffectType::Dispel))
+    }
+
+    pub fn heal_self(amount: i32) -> GameEffect {
+        GameEffect::enemy(
+            "Enemy Heals",
+            EffectTrigger::Always(EffectType::LifeAdjust(amount)),
         )
     }
 }
@@ -60,11 +185,8 @@ impl Enchantments {
                 element_type.description()
             ),
             EffectTrigger::Condition(
-                EffectCondition::PlayerHasCardWithElement(element_type.clone()),
-                EffectType::Damage(Damage {
-                    element_type,
-                    amount,
-                }),
+                EffectCondition::PlayerHasCardWithElement(CardZone::Hand, element_type.clone()),
+                EffectType::Damage(Damage::new(element_type, amount)),
             ),
         )
     }
@@ -92,6 +214,39 @@ impl Enchantments {
         )
     }
 
+    pub fn player_elem_spell_cost_adj(element_type: ElementType, amount: i32) -> GameEffect {
+        GameEffect::player(
+            &format!(
+                "{} Element Spells adjust power cost by {}",
+                element_type.description(),
+                amount
+            ),
+            EffectTrigger::Always(EffectType::Enchantment(Enchantment::PowerCostAdjust(
+                PowerCostAdjust {
+                    card_type: element_type,
+                    amount,
+                },
+            ))),
+        )
+    }
+
+    pub fn player_elem_resistance(
+        element_type: ElementType,
+        adjustment: DamageAdjustment,
+    ) -> GameEffect {
+        GameEffect::player(
+            &format!(
+                "{} Resistance {}",
+                element_type.description(),
+                adjustment.description()
+            ),
+            EffectTrigger::Always(EffectType::Enchantment(Enchantment::ElementResistance(
+                element_type,
+                adjustment,
+            ))),
+        )
+    }
+
     pub fn player_shield_from_elem(amount: i32) -> GameEffect {
         GameEffect::player(
             &format!("Global Shield {}", amount,),
@@ -99,6 +254,13 @@ impl Enchantments {
         )
     }
 
+    pub fn enemy_shield(amount: i32) -> GameEffect {
+        GameEffect::enemy(
+            &format!("Enemy Shield {}", amount),
+            EffectTrigger::Always(EffectType::Enchantment(Enchantment::ShieldDamage(amount))),
+        )
+    }
+
     pub fn player_heal_per_turn(amount: i32) -> GameEffect {
         GameEffect::player(
             &format!("Global Shield {}", amount,),
@@ -118,10 +280,7 @@ impl OnCardPlayEffects {
             ),
             EffectTrigger::Condition(
                 EffectCondition::PlayerPlaysCardWithElement(element_type.clone()),
-                EffectType::Damage(Damage {
-                    element_type,
-                    amount,
-                }),
+                EffectType::Damage(Damage::new(element_type, amount)),
             ),
         )
     }
@@ -160,6 +319,13 @@ impl OnCardPlayEffects {
             EffectTrigger::Discard(String::new()),
         )
     }
+
+    pub fn exile_this_card() -> GameEffect {
+        GameEffect::player(
+            "Exile this card after playing",
+            EffectTrigger::Exile(String::new()),
+        )
+    }
 }
 
 pub struct CardEffects;
@@ -168,26 +334,30 @@ impl CardEffects {
     pub fn do_element_damage(element_type: ElementType, amount: i32) -> GameEffect {
         GameEffect::enemy(
             &format!("{} Damage", element_type.description()),
-            EffectTrigger::Always(EffectType::Damage(Damage {
-                element_type,
-                amount,
-            })),
+            EffectTrigger::Always(EffectType::Damage(Damage::new(element_type, amount))),
+        )
+    }
+
+    pub fn do_element_damage_to_all(element_type: ElementType, amount: i32) -> GameEffect {
+        GameEffect::all_enemies(
+            &format!("{} Damage to All Enemies", element_type.description()),
+            EffectTrigger::Always(EffectType::Damage(Damage::new(element_type, amount))),
         )
     }
 
     pub fn do_physical_damage(amount: i32) -> GameEffect {
         GameEffect::enemy(
-            &format!("Physical Damage"),
-            EffectTrigger::Always(EffectType::Damage(Damage {
-                element_type: ElementType::NoElement,
+            "Physical Damage",
+            EffectTrigger::Always(EffectType::Damage(Damage::new(
+                ElementType::NoElement,
                 amount,
-            })),
+            ))),
         )
     }
 
     pub fn do_percent_damage(pct: f64) -> GameEffect {
         GameEffect::enemy(
-            &format!("Physical Damage"),
+            "Physical Damage",
             EffectTrigger::Always(EffectType::PercentDamage(pct)),
         )
     }
@@ -195,16 +365,53 @@ impl CardEffects {
     //Heals
     pub fn heal(amount: i32) -> GameEffect {
         GameEffect::player(
-            &format!("Heal"),
+            "Heal",
+            EffectTrigger::Always(EffectType::LifeAdjust(amount)),
+        )
+    }
+
+    pub fn heal_ally(amount: i32) -> GameEffect {
+        GameEffect::ally(
+            "Heal Ally",
             EffectTrigger::Always(EffectType::LifeAdjust(amount)),
         )
     }
 
     //Utility
-    pub fn skip_enemy_turn() -> GameEffect {
+    pub fn draw_cards(count: u32) -> GameEffect {
+        GameEffect::player("Draw Cards", EffectTrigger::Draw(count))
+    }
+
+    pub fn ally_draw_cards(count: u32) -> GameEffect {
+        GameEffect::ally("Ally Draws Cards", EffectTrigger::Draw(count))
+    }
+
+    pub fn afflict_enemy(status: Status) -> GameEffect {
         GameEffect::enemy(
-            &format!("Skip Turn"),
-            EffectTrigger::Always(EffectType::SkipTurn),
+            &format!("{} to Enemy", status.kind.description()),
+            EffectTrigger::Always(EffectType::Status(status)),
+        )
+    }
+
+    pub fn bless_ally(status: Status) -> GameEffect {
+        GameEffect::ally(
+            &format!("{} to Ally", status.kind.description()),
+            EffectTrigger::Always(EffectType::Status(status)),
         )
     }
+
+    pub fn enchant(enchantment: Enchantment) -> GameEffect {
+        GameEffect::player(
+            &format!("Enchant [{}]", enchantment.description()),
+            EffectTrigger::Always(EffectType::Enchantment(enchantment)),
+        )
+    }
+
+    pub fn dispel_enemy() -> GameEffect {
+        GameEffect::enemy("Dispel Enemy", EffectTrigger::Always(EffectType::Dispel))
+    }
+
+    pub fn skip_enemy_turn() -> GameEffect {
+        GameEffect::enemy("Skip Turn", EffectTrigger::Always(EffectType::SkipTurn))
+    }
 }
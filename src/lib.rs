@@ -1,45 +1,97 @@
+pub mod catalog;
+pub mod combatant;
+pub mod condition;
+pub mod damage;
 pub mod enemy;
+pub mod events;
 pub mod fp_vec;
 pub mod game;
 pub mod game_effects;
+pub mod phase;
 pub mod player;
+pub mod replay;
+pub mod rng;
+pub mod save;
+pub mod search;
+pub mod simulation;
+pub mod status;
+pub mod strategy;
+pub mod versioned;
 
 use crate::player::PlayerCard;
-use enemy::Enemy;
-use player::Player;
+use combatant::Combatant;
+use condition::{Comparison, Quantity, Situation};
+use events::GameEvent;
+use fp_vec::FpVec;
+use serde::{Deserialize, Serialize};
+use status::Status;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum EffectTarget {
+    /// Whoever played the card, or the player an enemy is attacking.
     Player,
+    /// A player by their position in the game.
+    PlayerAt(usize),
+    /// The ally chosen for a card, by default whoever played it.
+    Ally,
+    AllPlayers,
+    /// The card's target, or the enemy the effect belongs to.
     Enemy,
+    /// An enemy by its position in the encounter.
+    EnemyAt(usize),
+    AllEnemies,
 }
 
 impl EffectTarget {
     pub fn description(&self) -> String {
         match self {
             EffectTarget::Player => "PLAYER".to_string(),
+            EffectTarget::PlayerAt(idx) => format!("PLAYER #{}", idx + 1),
+            EffectTarget::Ally => "ALLY".to_string(),
+            EffectTarget::AllPlayers => "ALL PLAYERS".to_string(),
             EffectTarget::Enemy => "ENEMY".to_string(),
+            EffectTarget::EnemyAt(idx) => format!("ENEMY #{}", idx + 1),
+            EffectTarget::AllEnemies => "ALL ENEMIES".to_string(),
         }
     }
     pub fn is_player(&self) -> bool {
+        matches!(
+            self,
+            EffectTarget::Player
+                | EffectTarget::PlayerAt(_)
+                | EffectTarget::Ally
+                | EffectTarget::AllPlayers
+        )
+    }
+    pub fn is_enemy(&self) -> bool {
+        !self.is_player()
+    }
+
+    /// Pins `Enemy` down to the enemy at `enemy`.
+    pub fn aimed_at(self, enemy: usize) -> Self {
         match self {
-            EffectTarget::Player => true,
-            EffectTarget::Enemy => false,
+            EffectTarget::Enemy => EffectTarget::EnemyAt(enemy),
+            target => target,
         }
     }
-    pub fn is_enemy(&self) -> bool {
+
+    /// Pins `Player` down to the player at `player` and `Ally` to the one at `ally`.
+    pub fn aimed_at_player(self, player: usize, ally: usize) -> Self {
         match self {
-            EffectTarget::Player => false,
-            EffectTarget::Enemy => true,
+            EffectTarget::Player => EffectTarget::PlayerAt(player),
+            EffectTarget::Ally => EffectTarget::PlayerAt(ally),
+            target => target,
         }
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum EffectTrigger {
     Always(EffectType),
     Condition(EffectCondition, EffectType),
     Discard(String),
+    Exile(String),
+    Draw(u32),
 }
 
 impl EffectTrigger {
@@ -50,64 +102,153 @@ impl EffectTrigger {
                 format!("[COND - {}] {}", cond.description(), eff.description())
             }
             EffectTrigger::Discard(id) => format!("Discard card id: {}", id),
+            EffectTrigger::Exile(id) => format!("Exile card id: {}", id),
+            EffectTrigger::Draw(count) => format!("Draw {} card(s)", count),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum CardZone {
+    Library,
+    Hand,
+    Discard,
+    Exile,
+}
+
+impl CardZone {
+    pub fn description(&self) -> String {
+        match self {
+            CardZone::Library => "Library".to_string(),
+            CardZone::Hand => "Hand".to_string(),
+            CardZone::Discard => "Discard".to_string(),
+            CardZone::Exile => "Exile".to_string(),
         }
     }
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum EffectCondition {
-    PlayerHasCardWithElement(ElementType),
-    PlayerHasNoCardWithElement(ElementType),
+    PlayerHasCardWithElement(CardZone, ElementType),
+    PlayerHasNoCardWithElement(CardZone, ElementType),
     PlayerPlaysCardWithElement(ElementType),
+    EnemyHitPointsBelow(i32),
+    /// Below a fraction of the enemy's starting hit points.
+    EnemyHitPointsBelowPercent(f64),
+    /// Held directly or granted by a status.
+    EnemyHasEnchantment(Enchantment),
+    EnemySkippedLastTurn,
+    /// The enemy took damage of the element since the round began.
+    EnemyTookElementDamage(ElementType),
+    Compare(Quantity, Comparison, i32),
+    /// Holds when every condition does, including when there are none.
+    All(Vec<EffectCondition>),
+    /// Holds when at least one condition does.
+    Any(Vec<EffectCondition>),
+    Not(Box<EffectCondition>),
 }
 
 impl EffectCondition {
     pub fn description(&self) -> String {
         match self {
-            EffectCondition::PlayerHasCardWithElement(el) => {
-                format!("HAS_ELEMENT [{}]", el.description())
+            EffectCondition::PlayerHasCardWithElement(zone, el) => {
+                format!(
+                    "HAS_ELEMENT [{}] IN [{}]",
+                    el.description(),
+                    zone.description()
+                )
             }
-            EffectCondition::PlayerHasNoCardWithElement(el) => {
-                format!("HAS_NO_ELEMENT [{}]", el.description())
+            EffectCondition::PlayerHasNoCardWithElement(zone, el) => {
+                format!(
+                    "HAS_NO_ELEMENT [{}] IN [{}]",
+                    el.description(),
+                    zone.description()
+                )
             }
             EffectCondition::PlayerPlaysCardWithElement(el) => {
                 format!("PLAYS_SPELL_ELEMENT [{}]", el.description())
             }
-        }
-    }
-
-    pub fn check_player(&self, player: &Player) -> bool {
-        match self {
-            EffectCondition::PlayerHasCardWithElement(el) => {
-                player.cards.inner.iter().any(|card| card.element == *el)
+            EffectCondition::EnemyHitPointsBelow(hp) => format!("ENEMY_HP_BELOW [{}]", hp),
+            EffectCondition::EnemyHitPointsBelowPercent(pct) => {
+                format!("ENEMY_HP_BELOW [{}%]", pct * 100.0)
             }
-            EffectCondition::PlayerHasNoCardWithElement(el) => {
-                player.cards.inner.iter().all(|card| card.element != *el)
+            EffectCondition::EnemyHasEnchantment(ench) => {
+                format!("ENEMY_HAS_ENCHANTMENT [{}]", ench.description())
             }
-            _ => false,
+            EffectCondition::EnemySkippedLastTurn => "ENEMY_SKIPPED_LAST_TURN".to_string(),
+            EffectCondition::EnemyTookElementDamage(el) => {
+                format!("ENEMY_TOOK_ELEMENT [{}]", el.description())
+            }
+            EffectCondition::Compare(quantity, op, value) => {
+                format!("{} {} {}", quantity.description(), op.description(), value)
+            }
+            EffectCondition::All(conds) => format!(
+                "({})",
+                conds
+                    .iter()
+                    .map(|cond| cond.description())
+                    .collect::<Vec<String>>()
+                    .join(" AND ")
+            ),
+            EffectCondition::Any(conds) => format!(
+                "({})",
+                conds
+                    .iter()
+                    .map(|cond| cond.description())
+                    .collect::<Vec<String>>()
+                    .join(" OR ")
+            ),
+            EffectCondition::Not(cond) => format!("NOT {}", cond.description()),
         }
     }
 
-    pub fn check_player_card(&self, card: &PlayerCard) -> bool {
-        match self {
-            EffectCondition::PlayerPlaysCardWithElement(el) => card.element == *el,
-            _ => false,
-        }
-    }
-    pub fn check_enemy(&self, enemy: &Enemy) -> bool {
+    pub fn check(&self, situation: &Situation) -> bool {
+        let player = situation.player;
+        let enemy = situation.enemy;
         match self {
-            _ => true,
+            EffectCondition::PlayerHasCardWithElement(zone, el) => player
+                .zone(zone)
+                .inner
+                .iter()
+                .any(|card| card.element == *el),
+            EffectCondition::PlayerHasNoCardWithElement(zone, el) => player
+                .zone(zone)
+                .inner
+                .iter()
+                .all(|card| card.element != *el),
+            EffectCondition::PlayerPlaysCardWithElement(el) => {
+                situation.card.is_some_and(|card| card.element == *el)
+            }
+            EffectCondition::EnemyHitPointsBelow(hp) => enemy.hit_points < *hp,
+            EffectCondition::EnemyHitPointsBelowPercent(pct) => {
+                (enemy.hit_points as f64) < enemy.max_hit_points as f64 * pct
+            }
+            EffectCondition::EnemyHasEnchantment(ench) => enemy.enchantments().inner.contains(ench),
+            EffectCondition::EnemySkippedLastTurn => enemy.skipped_last_turn,
+            EffectCondition::EnemyTookElementDamage(el) => enemy.damage_taken.inner.contains(el),
+            EffectCondition::Compare(quantity, op, value) => {
+                op.holds(quantity.value(situation), *value)
+            }
+            EffectCondition::All(conds) => conds.iter().all(|cond| cond.check(situation)),
+            EffectCondition::Any(conds) => conds.iter().any(|cond| cond.check(situation)),
+            EffectCondition::Not(cond) => !cond.check(situation),
         }
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum EffectType {
     Damage(Damage),
     LifeAdjust(i32),
     Enchantment(Enchantment),
+    /// Swaps the first enchantment for the second, granting it even if the first isn't held.
+    ReplaceEnchantment(Enchantment, Enchantment),
+    RemoveEnchantment(Enchantment),
+    /// Strips every enchantment from the target, status-granted ones included.
+    Dispel,
     PercentDamage(f64),
     SkipTurn,
+    Status(Status),
 }
 
 impl EffectType {
@@ -118,18 +259,31 @@ impl EffectType {
             }
             EffectType::LifeAdjust(adj) => format!("Life {}", adj),
             EffectType::Enchantment(ench) => format!("Enchant [{}]", ench.description()),
+            EffectType::ReplaceEnchantment(old, new) => format!(
+                "Replace Enchantment [{}] with [{}]",
+                old.description(),
+                new.description()
+            ),
+            EffectType::RemoveEnchantment(ench) => {
+                format!("Remove Enchantment [{}]", ench.description())
+            }
+            EffectType::Dispel => "Dispel".to_string(),
             EffectType::PercentDamage(dmg) => format!("Damage {}%", dmg),
-            EffectType::SkipTurn => format!("Skip Turn"),
+            EffectType::SkipTurn => "Skip Turn".to_string(),
+            EffectType::Status(status) => format!("Status [{}]", status.description()),
         }
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum Enchantment {
     SpellDamageAdjust(ElementType, i32),
     ShieldDamage(i32),
     LifeAdjPerTurn(i32),
     SpellElementForbidden(ElementType),
+    PowerCostAdjust(PowerCostAdjust),
+    /// Adjusts damage of the element taken by whoever holds it.
+    ElementResistance(ElementType, DamageAdjustment),
 }
 
 impl Enchantment {
@@ -147,26 +301,93 @@ impl Enchantment {
             Enchantment::SpellElementForbidden(elem) => {
                 format!("{} Spells Forbidden", elem.description())
             }
+            Enchantment::PowerCostAdjust(adj) => adj.description(),
+            Enchantment::ElementResistance(elem, adj) => {
+                format!("{} Resistance {}", elem.description(), adj.description())
+            }
+        }
+    }
+
+    /// The enchantments `target` holds after `effect`, with the events it raises.
+    pub fn resolve(
+        held: FpVec<Enchantment>,
+        effect: &EffectType,
+        target: EffectTarget,
+    ) -> (FpVec<Enchantment>, FpVec<GameEvent>) {
+        match effect {
+            EffectType::Enchantment(ench) => (
+                held.push(ench.clone()),
+                FpVec::from_vec(vec![GameEvent::EnchantmentActivated {
+                    target,
+                    enchantment: ench.clone(),
+                }]),
+            ),
+            EffectType::ReplaceEnchantment(old, new) => {
+                let (held, removed) = Self::resolve(
+                    held,
+                    &EffectType::RemoveEnchantment(old.clone()),
+                    target.clone(),
+                );
+                let (held, granted) =
+                    Self::resolve(held, &EffectType::Enchantment(new.clone()), target);
+                (held, removed.extend(granted))
+            }
+            EffectType::RemoveEnchantment(ench) => {
+                match held.inner.iter().position(|h| h == ench) {
+                    Some(idx) => {
+                        let mut inner = held.inner;
+                        let enchantment = inner.remove(idx);
+                        (
+                            FpVec::from_vec(inner),
+                            FpVec::from_vec(vec![GameEvent::EnchantmentRemoved {
+                                target,
+                                enchantment,
+                            }]),
+                        )
+                    }
+                    None => (held, FpVec::new()),
+                }
+            }
+            EffectType::Dispel => {
+                let events = held
+                    .inner
+                    .into_iter()
+                    .map(|enchantment| GameEvent::EnchantmentRemoved {
+                        target: target.clone(),
+                        enchantment,
+                    })
+                    .collect();
+                (FpVec::new(), FpVec::from_vec(events))
+            }
+            _ => (held, FpVec::new()),
         }
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Damage {
     pub amount: i32,
     pub element_type: ElementType,
+    /// What the attacker adds on top of `amount`.
+    #[serde(default)]
+    pub bonus: i32,
 }
 
 impl Damage {
-    pub fn raw(amount: i32) -> Self {
+    pub fn new(element_type: ElementType, amount: i32) -> Self {
         Self {
-            element_type: ElementType::NoElement,
+            element_type,
             amount,
+            bonus: 0,
         }
     }
+
+    pub fn raw(amount: i32) -> Self {
+        Self::new(ElementType::NoElement, amount)
+    }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DefenseProps {
     pub wind: DamageAdjustment,
     pub water: DamageAdjustment,
@@ -174,7 +395,19 @@ pub struct DefenseProps {
     pub any: DamageAdjustment,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+impl DefenseProps {
+    /// No adjustment to any element.
+    pub fn normal() -> Self {
+        Self {
+            wind: DamageAdjustment::Normal,
+            water: DamageAdjustment::Normal,
+            land: DamageAdjustment::Normal,
+            any: DamageAdjustment::Normal,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum ElementType {
     Wind,
     Land,
@@ -193,7 +426,7 @@ impl ElementType {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum DamageAdjustment {
     Percent(f64),
     Absolute(i32),
@@ -201,6 +434,14 @@ pub enum DamageAdjustment {
 }
 
 impl DamageAdjustment {
+    pub fn description(&self) -> String {
+        match self {
+            DamageAdjustment::Absolute(val) => format!("{:+}", val),
+            DamageAdjustment::Percent(val) => format!("x{}", val),
+            DamageAdjustment::Normal => "Normal".to_string(),
+        }
+    }
+
     pub fn adjust_damage(&self, damage: i32) -> i32 {
         match self {
             DamageAdjustment::Absolute(val) => damage + val,
@@ -210,8 +451,26 @@ impl DamageAdjustment {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PowerCostAdjust {
     pub card_type: ElementType,
     pub amount: i32,
 }
+
+impl PowerCostAdjust {
+    pub fn description(&self) -> String {
+        format!(
+            "{} Spells cost {:+} power",
+            self.card_type.description(),
+            self.amount
+        )
+    }
+
+    pub fn adjust_cost(&self, card: &PlayerCard, cost: i32) -> i32 {
+        if card.element == self.card_type {
+            cost + self.amount
+        } else {
+            cost
+        }
+    }
+}
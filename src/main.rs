@@ -1,133 +1,338 @@
-#![feature(let_chains)]
-
-use card_game_simulator;
+use card_game_simulator::catalog::{Catalog, CatalogError};
 use card_game_simulator::game::GameOutcome;
-use card_game_simulator::game_effects::{CardEffects, Enchantments, OnCardPlayEffects};
-use card_game_simulator::player::SpecialCards;
+use card_game_simulator::replay::Replay;
+use card_game_simulator::save::{load_game, save_game, save_replay};
+use card_game_simulator::search::{best_play, SearchConfig};
+use card_game_simulator::strategy::PlayerStrategy;
 use card_game_simulator::{
-    enemy::Enemy,
     fp_vec::FpVec,
     game::Game,
-    player::{Player, PlayerCard},
-    ElementType,
+    player::{CardPlay, Player},
 };
 
-pub fn init_game() -> Game {
-    let player_card_1 = PlayerCard::new("Gust", "Play to cause 3 Wind Damage.", ElementType::Wind)
-        .play_card_effect(CardEffects::do_element_damage(ElementType::Wind, 3));
-
-    let player_card_2 =
-        PlayerCard::new("Stream", "Play to cause 3 Land Damage.", ElementType::Land)
-            .play_card_effect(CardEffects::do_element_damage(ElementType::Land, 3));
-
-    let player_card_3 =
-        PlayerCard::new("First Aid", "Play to head 8 hit points.", ElementType::Land)
-            .play_card_effect(CardEffects::heal(8));
-
-    let player_card_4 = SpecialCards::fire_hose();
-    let player = Player::new(
-        20,
-        FpVec::from_vec(vec![
-            player_card_1,
-            player_card_2,
-            player_card_3,
-            player_card_4,
-        ]),
-    );
+const STARTER_DECK: [&str; 4] = ["gust", "stream", "first_aid", "fire_hose"];
+const STARTER_ENEMY: &str = "blackout";
 
-    let enemy = Enemy::blackout();
+pub fn init_game(
+    catalog: &Catalog,
+    enemies: &[&str],
+    players: usize,
+    seed: Option<u64>,
+) -> Result<Replay, CatalogError> {
+    let player = Player::new(20, catalog.cards(&STARTER_DECK)?);
+    let enemies = catalog.encounter(enemies)?;
 
-    Game::start(enemy, player)
+    Ok(Replay::coop(
+        enemies,
+        FpVec::from_vec(vec![player; players.max(1)]),
+        seed.unwrap_or_else(rand::random),
+    ))
 }
 
-fn get_card_numbers() -> Result<Vec<usize>, ()> {
-    let term = std::io::stdin();
-    println!("Enter card #s to play with ',' between (or x to quit):");
-    let mut command = String::new();
-    term.read_line(&mut command).unwrap();
-    if command.trim() == "x" {
-        println!("Quitting");
-        Err(())
-    } else {
-        let card_numbers: Vec<usize> = command
-            .split(',')
-            .into_iter()
-            .flat_map(|data| data.trim().parse::<usize>())
-            .collect();
+struct StderrLogger;
+
+impl log::Log for StderrLogger {
+    fn enabled(&self, _metadata: &log::Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &log::Record) {
+        eprintln!("[{}] {}", record.level(), record.args());
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: StderrLogger = StderrLogger;
+
+fn init_logging() {
+    if std::env::args().any(|arg| arg == "--verbose") {
+        if let Ok(()) = log::set_logger(&LOGGER) {
+            log::set_max_level(log::LevelFilter::Debug);
+        }
+    }
+}
+
+fn arg_value(args: &[String], flag: &str) -> Option<Result<String, String>> {
+    args.iter().position(|arg| arg == flag).map(|idx| {
+        args.get(idx + 1)
+            .cloned()
+            .ok_or_else(|| format!("{} requires a value", flag))
+    })
+}
+
+struct Recording {
+    path: String,
+    replay: Replay,
+}
+
+fn setup_game() -> Result<(Game, Option<Recording>), Box<dyn std::error::Error>> {
+    let args: Vec<String> = std::env::args().collect();
+    let record = arg_value(&args, "--record").transpose()?;
+    match arg_value(&args, "--resume") {
+        Some(_) if record.is_some() => Err("--record only works for new games".into()),
+        Some(path) => Ok((load_game(path?)?, None)),
+        None => {
+            let catalog = match arg_value(&args, "--catalog") {
+                Some(path) => Catalog::load(path?)?,
+                None => Catalog::builtin(),
+            };
+            let seed = match arg_value(&args, "--seed") {
+                Some(seed) => Some(seed?.parse::<u64>()?),
+                None => None,
+            };
+            let enemies = arg_value(&args, "--enemies")
+                .transpose()?
+                .unwrap_or_else(|| STARTER_ENEMY.to_string());
+            let enemies: Vec<&str> = enemies.split(',').map(|key| key.trim()).collect();
+            let players = match arg_value(&args, "--players") {
+                Some(players) => players?.parse::<usize>()?,
+                None => 1,
+            };
+            let replay = init_game(&catalog, &enemies, players, seed)?;
+            Ok((
+                replay.start(),
+                record.map(|path| Recording { path, replay }),
+            ))
+        }
+    }
+}
+
+enum Control {
+    Load(String),
+    Quit,
+}
+
+/// Asks which cards to play.  Saves here; loads and quits are left in `control`.
+struct PromptStrategy {
+    control: Option<Control>,
+}
 
-        Ok(card_numbers)
+impl PromptStrategy {
+    fn take_control(&mut self) -> Option<Control> {
+        self.control.take()
+    }
+}
+
+impl PlayerStrategy for PromptStrategy {
+    fn name(&self) -> String {
+        "prompt".to_string()
+    }
+
+    fn choose_cards(&mut self, game: &Game) -> FpVec<CardPlay> {
+        loop {
+            let term = std::io::stdin();
+            println!(
+                "Enter card #s to play with ',' between, card#@enemy# to pick a target, card#>player# to pick an ally (or hint, save <file>, load <file>, x to quit):"
+            );
+            let mut command = String::new();
+            term.read_line(&mut command).unwrap();
+            let command = command.trim();
+            match command.split_once(' ') {
+                _ if command == "x" => {
+                    println!("Quitting");
+                    self.control = Some(Control::Quit);
+                    return FpVec::new();
+                }
+                _ if command == "hint" => {
+                    println!(
+                        "{}",
+                        best_play(game, &SearchConfig::default(), &mut rand::thread_rng())
+                            .description()
+                    )
+                }
+                Some(("save", path)) => match save_game(game, path.trim()) {
+                    Ok(()) => println!("Game saved to {}", path.trim()),
+                    Err(err) => println!("Could not save game: {}", err),
+                },
+                Some(("load", path)) => {
+                    self.control = Some(Control::Load(path.trim().to_string()));
+                    return FpVec::new();
+                }
+                _ => {
+                    return command
+                        .split(',')
+                        .flat_map(|data| parse_play(game, data.trim()))
+                        .fold(FpVec::new(), |plays: FpVec<CardPlay>, play| {
+                            if plays
+                                .inner
+                                .iter()
+                                .any(|other| other.card.id == play.card.id)
+                            {
+                                println!("{} can only be played once", play.card.name);
+                                plays
+                            } else {
+                                plays.push(play)
+                            }
+                        });
+                }
+            }
+        }
+    }
+}
+
+fn list_number<T>(items: &[T], number: usize) -> Option<&T> {
+    number.checked_sub(1).and_then(|idx| items.get(idx))
+}
+
+// Parses `card`, optionally followed by `@enemy` and `>player`, numbered from 1.
+fn parse_play(game: &Game, data: &str) -> Option<CardPlay> {
+    let (data, ally) = match data.split_once('>') {
+        Some((data, ally)) => (data, Some(ally)),
+        None => (data, None),
+    };
+    let (card, enemy) = match data.split_once('@') {
+        Some((card, enemy)) => (card, Some(enemy)),
+        None => (data, None),
+    };
+    let number = card.trim().parse::<usize>().ok()?;
+    let card = match list_number(&game.player().hand.inner, number) {
+        Some(card) => card.clone(),
+        None => {
+            println!("Invalid card: {}", number);
+            return None;
+        }
+    };
+    let play = match enemy.map(|enemy| enemy.trim().parse::<usize>()) {
+        None => CardPlay::new(card),
+        Some(Ok(number)) if list_number(&game.enemies.inner, number).is_some() => {
+            CardPlay::new(card).at(number - 1)
+        }
+        Some(_) => {
+            println!("Invalid enemy for {}", card.name);
+            return None;
+        }
+    };
+    match ally.map(|ally| ally.trim().parse::<usize>()) {
+        None => Some(play),
+        Some(Ok(number)) if list_number(&game.players.inner, number).is_some() => {
+            Some(play.for_ally(number - 1))
+        }
+        Some(_) => {
+            println!("Invalid ally for {}", play.card.name);
+            None
+        }
     }
 }
 
 fn print_cards(game: &Game) {
-    println!("The cards are:");
+    let player = game.player();
+    if game.players.inner.len() > 1 {
+        println!("Player #{}'s turn", game.current_player + 1);
+    }
+    println!("The cards in hand are:");
     println!(
         "{}",
-        game.player
-            .cards
+        player
+            .hand
             .inner
             .iter()
             .fold((1, String::new()), |(number, text), card| {
                 (
                     number + 1,
                     format!(
-                        "{}{}\n",
+                        "{}{}. {} [{} power]{} - {}\n",
                         text,
-                        format!(
-                            "{}. {} {} - {}",
-                            number,
-                            card.name,
-                            if card.can_play { "" } else { " (CAN'T PLAY)" },
-                            card.description
-                        )
+                        number,
+                        card.name,
+                        player.card_cost(card),
+                        if card.can_play { "" } else { " (CAN'T PLAY)" },
+                        card.description
                     ),
                 )
             })
             .1
     );
     println!("Game turn start: Turn {}", game.turn_number);
-    println!("Player Status: {}", game.player.description());
-    println!("Enemy Status: {}", game.enemy.description());
+    game.players
+        .inner
+        .iter()
+        .enumerate()
+        .for_each(|(idx, player)| match game.players.inner.len() {
+            1 => println!("Player Status: {}", player.description()),
+            _ => println!("Player #{} Status: {}", idx + 1, player.description()),
+        });
+    game.enemies
+        .inner
+        .iter()
+        .enumerate()
+        .for_each(|(idx, enemy)| println!("Enemy #{} Status: {}", idx + 1, enemy.description()));
+}
+
+fn print_events(game: &Game) {
+    game.events
+        .inner
+        .iter()
+        .for_each(|event| println!("{}", event.description()));
 }
 
-fn game_loop(mut game: Game) -> Game {
+fn game_loop(
+    mut game: Game,
+    strategy: &mut PromptStrategy,
+    mut recording: Option<Recording>,
+) -> Game {
+    print_events(&game);
     print_cards(&game);
 
-    while game.game_result == GameOutcome::Undecided && let Ok(card_numbers) = get_card_numbers() {
-        let cards: FpVec<PlayerCard> =
-            card_numbers
-                .into_iter()
-                .fold(FpVec::new(), |cards, number| {
-                    match game.player.cards.inner.get(number - 1) {
-                        Some(c) => cards.push(c.clone()),
-                        None => {
-                            println!("Invalid card: {}", number);
-                            cards
+    while game.game_result == GameOutcome::Undecided {
+        let cards = strategy.choose_cards(&game);
+        match strategy.take_control() {
+            Some(Control::Quit) => break,
+            Some(Control::Load(path)) => {
+                match load_game(&path) {
+                    Ok(loaded) => {
+                        println!("Game loaded from {}", path);
+                        if let Some(Recording { path, .. }) = recording.take() {
+                            println!("Stopped recording to {}", path);
                         }
+                        game = loaded;
+                        print_cards(&game);
                     }
-                });
-
-        game = game.take_player_turn(cards);
-        if game.game_result == GameOutcome::Undecided {
-            game = game.take_enemy_turn();
+                    Err(err) => println!("Could not load game: {}", err),
+                }
+                continue;
+            }
+            None => (),
         }
+
+        game = game.take_turn(cards.clone());
+        print_events(&game);
+        recording = recording.map(|Recording { path, replay }| {
+            let replay = replay.record(&cards, &game);
+            if let Err(err) = save_replay(&replay, &path) {
+                println!("Could not record turn: {}", err);
+            }
+            Recording { path, replay }
+        });
         println!("-----------------------------------");
         print_cards(&game);
-
     }
     game
 }
 
 fn main() {
-    let game = init_game();
-    let out = game_loop(game);
+    init_logging();
+    let (game, recording) = match setup_game() {
+        Ok(setup) => setup,
+        Err(err) => {
+            println!("Could not set up game: {}", err);
+            std::process::exit(1);
+        }
+    };
+    println!("Game seed: {}", game.seed);
+    let out = game_loop(game, &mut PromptStrategy { control: None }, recording);
+    let winner = match out.game_result {
+        GameOutcome::Draw(turn) => {
+            println!("Game finished.  Both sides fell together on turn #{}", turn);
+            return;
+        }
+        GameOutcome::Undecided => "No one",
+        GameOutcome::PlayerWins(_) if out.players.inner.len() > 1 => "The players",
+        GameOutcome::PlayerWins(_) => "The Player",
+        GameOutcome::EnemyWins(_) => "The enemy",
+    };
     println!(
         "Game finished.  {} won on turn #{}",
-        match out.game_result {
-            GameOutcome::Undecided => "No one",
-            GameOutcome::PlayerWins(_) => "The Player",
-            GameOutcome::EnemyWins(_) => "The enemy",
-        },
-        out.turn_number
+        winner, out.turn_number
     );
 }
@@ -0,0 +1,82 @@
+use crate::fp_vec::FpVec;
+use crate::game_effects::GameEffect;
+use serde::{Deserialize, Serialize};
+
+/// Each round runs upkeep, each player's main and end, each enemy's turn, then cleanup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Phase {
+    GameStart,
+    PlayerUpkeep,
+    PlayerMain,
+    PlayerEnd,
+    EnemyUpkeep,
+    EnemyAction,
+    EnemyEnd,
+    Cleanup,
+}
+
+impl Phase {
+    pub fn description(&self) -> String {
+        match self {
+            Phase::GameStart => "game start".to_string(),
+            Phase::PlayerUpkeep => "player upkeep".to_string(),
+            Phase::PlayerMain => "player main".to_string(),
+            Phase::PlayerEnd => "player end".to_string(),
+            Phase::EnemyUpkeep => "enemy upkeep".to_string(),
+            Phase::EnemyAction => "enemy action".to_string(),
+            Phase::EnemyEnd => "enemy end".to_string(),
+            Phase::Cleanup => "cleanup".to_string(),
+        }
+    }
+
+    /// Whether the game waits here for the current player's cards.
+    pub fn awaits_player(&self) -> bool {
+        *self == Phase::PlayerMain
+    }
+
+    /// Whether the game waits here for the enemies' turn.
+    pub fn awaits_enemies(&self) -> bool {
+        *self == Phase::EnemyUpkeep
+    }
+
+    /// Whether the phase is on the players' side; the game start counts as theirs.
+    pub fn players_turn(&self) -> bool {
+        matches!(
+            self,
+            Phase::GameStart | Phase::PlayerUpkeep | Phase::PlayerMain | Phase::PlayerEnd
+        )
+    }
+}
+
+/// An effect that resolves as a phase begins.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PhaseHook {
+    pub phase: Phase,
+    pub effect: GameEffect,
+}
+
+impl PhaseHook {
+    pub fn new(phase: Phase, effect: GameEffect) -> Self {
+        Self { phase, effect }
+    }
+
+    pub fn description(&self) -> String {
+        format!(
+            "{}: {}",
+            self.phase.description(),
+            self.effect.description()
+        )
+    }
+}
+
+/// The effects of `hooks` for `phase`, in order.
+pub fn effects_for(hooks: &FpVec<PhaseHook>, phase: Phase) -> FpVec<GameEffect> {
+    FpVec::from_vec(
+        hooks
+            .inner
+            .iter()
+            .filter(|hook| hook.phase == phase)
+            .map(|hook| hook.effect.clone())
+            .collect(),
+    )
+}
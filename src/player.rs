@@ -1,56 +1,273 @@
+use crate::combatant::{self, Combatant, Vitals};
+use crate::condition::{Comparison, Quantity, Situation};
+use crate::damage::DamageRules;
+use crate::events::{BlockReason, GameEvent};
 use crate::fp_vec::FpVec;
 use crate::game_effects::{CardEffects, Enchantments, OnCardPlayEffects};
+use crate::phase::{Phase, PhaseHook};
+use crate::rng::GameRng;
+use crate::status::Status;
 use crate::{
-    enemy::Enemy, game_effects::GameEffect, Damage, EffectCondition, EffectTrigger, EffectType,
-    ElementType, Enchantment,
+    enemy::Enemy, game_effects::GameEffect, CardZone, Damage, DamageAdjustment, DefenseProps,
+    EffectCondition, EffectTarget, EffectTrigger, EffectType, ElementType, Enchantment,
 };
+use rand::seq::SliceRandom;
+use serde::{Deserialize, Serialize};
+use std::cmp::max;
 use uuid::Uuid;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Player {
-    pub cards: FpVec<PlayerCard>,
+    pub library: FpVec<PlayerCard>,
+    pub hand: FpVec<PlayerCard>,
+    pub discard_pile: FpVec<PlayerCard>,
+    pub exile: FpVec<PlayerCard>,
+    pub starting_hand_size: usize,
+    pub draw_per_turn: u32,
+    pub power: i32,
+    pub power_per_turn: i32,
     pub hit_points: i32,
+    /// Resistances and weaknesses to each element.
+    #[serde(default = "DefenseProps::normal")]
+    pub defense_props: DefenseProps,
     pub current_activated_effects: FpVec<Enchantment>,
+    #[serde(default)]
+    pub statuses: FpVec<Status>,
+    /// Cards played so far this turn.
+    #[serde(default)]
+    pub cards_played: u32,
 }
 
 impl Player {
+    /// `cards` is the library, drawn from the front; by default all of it is the starting hand.
     pub fn new(hit_points: i32, cards: FpVec<PlayerCard>) -> Self {
         Self {
-            cards,
+            starting_hand_size: cards.inner.len(),
+            draw_per_turn: 0,
+            power: 0,
+            power_per_turn: 0,
+            library: cards,
+            hand: FpVec::new(),
+            discard_pile: FpVec::new(),
+            exile: FpVec::new(),
             hit_points,
+            defense_props: DefenseProps::normal(),
             current_activated_effects: FpVec::new(),
+            statuses: FpVec::new(),
+            cards_played: 0,
+        }
+    }
+
+    pub fn defense_props(self, defense_props: DefenseProps) -> Self {
+        Self {
+            defense_props,
+            ..self
+        }
+    }
+
+    pub fn starting_hand(self, starting_hand_size: usize) -> Self {
+        Self {
+            starting_hand_size,
+            ..self
+        }
+    }
+
+    pub fn draws_per_turn(self, draw_per_turn: u32) -> Self {
+        Self {
+            draw_per_turn,
+            ..self
+        }
+    }
+
+    pub fn power_per_turn(self, power_per_turn: i32) -> Self {
+        Self {
+            power_per_turn,
+            ..self
+        }
+    }
+
+    pub fn refill_power(self) -> Self {
+        Self {
+            power: self.power_per_turn,
+            ..self
+        }
+    }
+
+    /// Refills the player's power and resets the count of cards played.
+    pub fn begin_turn(self) -> Self {
+        Self {
+            cards_played: 0,
+            ..self.refill_power()
+        }
+    }
+
+    pub fn zone(&self, zone: &CardZone) -> &FpVec<PlayerCard> {
+        match zone {
+            CardZone::Library => &self.library,
+            CardZone::Hand => &self.hand,
+            CardZone::Discard => &self.discard_pile,
+            CardZone::Exile => &self.exile,
+        }
+    }
+
+    /// Gives every card the player owns a new id drawn from `rng`.
+    pub fn assign_card_ids(self, rng: &mut GameRng) -> Self {
+        let mut assign = |cards: FpVec<PlayerCard>| {
+            FpVec::from_vec(
+                cards
+                    .inner
+                    .into_iter()
+                    .map(|card| PlayerCard {
+                        id: rng.card_id(),
+                        ..card
+                    })
+                    .collect(),
+            )
+        };
+        Self {
+            library: assign(self.library),
+            hand: assign(self.hand),
+            discard_pile: assign(self.discard_pile),
+            exile: assign(self.exile),
+            ..self
+        }
+    }
+
+    pub fn draw_starting_hand(self, rng: &mut GameRng) -> (Self, FpVec<GameEvent>) {
+        let count = self.starting_hand_size;
+        self.draw_cards(count, rng)
+    }
+
+    pub fn draw_cards(self, count: usize, rng: &mut GameRng) -> (Self, FpVec<GameEvent>) {
+        (0..count).fold((self, FpVec::new()), |(player, events), _| {
+            let (player, drawn) = player.draw_card(rng);
+            (player, events.extend(drawn))
+        })
+    }
+
+    fn draw_card(self, rng: &mut GameRng) -> (Self, FpVec<GameEvent>) {
+        let (player, events) =
+            if self.library.inner.is_empty() && !self.discard_pile.inner.is_empty() {
+                let cards = self.discard_pile.inner.len();
+                (
+                    self.reshuffle_discard(rng),
+                    FpVec::from_vec(vec![GameEvent::DiscardReshuffled { cards }]),
+                )
+            } else {
+                (self, FpVec::new())
+            };
+        let mut library = player.library.inner;
+        if library.is_empty() {
+            (
+                Self {
+                    library: FpVec::from_vec(library),
+                    ..player
+                },
+                events.push(GameEvent::NoCardsToDraw),
+            )
+        } else {
+            let card = library.remove(0);
+            let event = GameEvent::CardDrawn {
+                card: card.name.clone(),
+            };
+            (
+                Self {
+                    library: FpVec::from_vec(library),
+                    hand: player.hand.push(card),
+                    ..player
+                },
+                events.push(event),
+            )
+        }
+    }
+
+    fn reshuffle_discard(self, rng: &mut GameRng) -> Self {
+        let mut library = self.discard_pile.inner;
+        library.shuffle(rng);
+        Self {
+            library: FpVec::from_vec(library),
+            discard_pile: FpVec::new(),
+            ..self
+        }
+    }
+
+    fn card_name(&self, id: &str) -> String {
+        self.hand
+            .inner
+            .iter()
+            .find(|card| card.id == id)
+            .map(|card| card.name.clone())
+            .unwrap_or_else(|| id.to_string())
+    }
+
+    fn move_from_hand(self, id: &str, to: CardZone) -> Self {
+        let (moved, hand): (Vec<PlayerCard>, Vec<PlayerCard>) =
+            self.hand.inner.into_iter().partition(|card| card.id == id);
+        let moved = FpVec::from_vec(moved);
+        let hand = FpVec::from_vec(hand);
+        match to {
+            CardZone::Library => Self {
+                library: self.library.extend(moved),
+                hand,
+                ..self
+            },
+            CardZone::Hand => Self {
+                hand: hand.extend(moved),
+                ..self
+            },
+            CardZone::Discard => Self {
+                discard_pile: self.discard_pile.extend(moved),
+                hand,
+                ..self
+            },
+            CardZone::Exile => Self {
+                exile: self.exile.extend(moved),
+                hand,
+                ..self
+            },
         }
     }
 
     pub fn description(&self) -> String {
         format!(
-            "HP [{}]\n  * Enchantment Effects [{}]",
+            "HP [{}] Power [{}/{}]\n  * Library [{}] Hand [{}] Discard [{}] Exile [{}]\n  * Enchantment Effects [{}]\n  * Statuses [{}]",
             self.hit_points,
-            self.current_activated_effects
+            self.power,
+            self.power_per_turn,
+            self.library.inner.len(),
+            self.hand.inner.len(),
+            self.discard_pile.inner.len(),
+            self.exile.inner.len(),
+            self.enchantments()
                 .inner
                 .iter()
                 .map(|eff| eff.description())
                 .collect::<Vec<String>>()
                 .join(", "),
+            self.statuses
+                .inner
+                .iter()
+                .map(|status| status.description())
+                .collect::<Vec<String>>()
+                .join(", "),
         )
     }
 
-    pub fn player_play_card(&self, enemy: &Enemy, card: PlayerCard) -> FpVec<GameEffect> {
-        // Check global enchantments
-        let card = self
-            .current_activated_effects
+    /// `card` with the player's active enchantments applied, as when it is played.
+    pub fn enchant_card(&self, card: PlayerCard) -> PlayerCard {
+        self.enchantments()
             .inner
             .iter()
             .fold(card, |card, eff| match eff {
                 Enchantment::SpellDamageAdjust(element, adj) if *element == card.element => {
                     PlayerCard {
-                        play_card_effects: card.play_card_effects.push(GameEffect::enemy(
-                            "Spell Damage Adjust Effect",
-                            EffectTrigger::Always(EffectType::Damage(Damage {
-                                element_type: element.clone(),
-                                amount: *adj,
-                            })),
-                        )),
+                        play_card_effects: FpVec::from_vec(
+                            card.play_card_effects
+                                .inner
+                                .into_iter()
+                                .map(|effect| effect.damage_bonus(*adj))
+                                .collect(),
+                        ),
                         ..card
                     }
                 }
@@ -59,71 +276,174 @@ impl Player {
                     ..card
                 },
                 _ => card,
+            })
+    }
+
+    pub fn card_cost(&self, card: &PlayerCard) -> i32 {
+        let cost = self
+            .enchantments()
+            .inner
+            .iter()
+            .fold(card.cost, |cost, eff| match eff {
+                Enchantment::PowerCostAdjust(adj) => adj.adjust_cost(card, cost),
+                _ => cost,
             });
+        max(cost, 0)
+    }
 
-        if !card.can_play {
-            println!("Cannot play card: {}", card.name);
-            FpVec::new()
-        } else {
-            println!("Play card: {}", card.name,);
-            let enemy_thorns_effects: FpVec<GameEffect> = enemy
-                .player_play_card_effects
+    /// Plays `play` against the board as it stands; None means the card was blocked.
+    pub fn play_card(
+        &self,
+        me: usize,
+        enemies: &FpVec<Enemy>,
+        players: &FpVec<Player>,
+        play: CardPlay,
+        played: &FpVec<String>,
+        turn_number: u32,
+    ) -> (Self, Option<PlayedCard>, FpVec<GameEvent>) {
+        let blocked = |card: PlayerCard, reason: BlockReason| {
+            let event = GameEvent::CardBlocked {
+                card: card.name,
+                reason,
+            };
+            (self.clone(), None, FpVec::from_vec(vec![event]))
+        };
+        let in_hand = self
+            .hand
+            .inner
+            .iter()
+            .find(|card| card.id == play.card.id && !played.inner.contains(&card.id));
+        let card = match in_hand {
+            Some(card) => self.enchant_card(card.clone()),
+            None => return blocked(play.card, BlockReason::NotInHand),
+        };
+        let cost = self.card_cost(&card);
+        let target = play
+            .target
+            .unwrap_or_else(|| combatant::first_standing(enemies));
+        let ally = play.ally.unwrap_or(me);
+        match 0 {
+            _ if !card.can_play => blocked(card, BlockReason::Forbidden),
+            _ if cost > self.power => blocked(
+                card,
+                BlockReason::NotEnoughPower {
+                    cost,
+                    available: self.power,
+                },
+            ),
+            _ if !combatant::standing(enemies).contains(&target) => {
+                blocked(card, BlockReason::TargetDown)
+            }
+            _ if !combatant::standing(players).contains(&ally) => {
+                blocked(card, BlockReason::AllyDown)
+            }
+            _ => {
+                let cards_played = self.cards_played + 1;
+                let play = CardPlay {
+                    card,
+                    target: Some(target),
+                    ally: Some(ally),
+                };
+                let (card_played, events) =
+                    self.resolve_card(me, enemies, play, turn_number, cards_played);
+                let player = Self {
+                    power: self.power - cost,
+                    cards_played,
+                    ..self.clone()
+                };
+                (player, Some(card_played), events)
+            }
+        }
+    }
+
+    // Every enemy still standing reacts to the card when its counter's condition holds.
+    fn resolve_card(
+        &self,
+        me: usize,
+        enemies: &FpVec<Enemy>,
+        play: CardPlay,
+        turn_number: u32,
+        cards_played: u32,
+    ) -> (PlayedCard, FpVec<GameEvent>) {
+        let target = play
+            .target
+            .unwrap_or_else(|| combatant::first_standing(enemies));
+        let ally = play.ally.unwrap_or(me);
+        let card = play.card;
+        let played = GameEvent::CardPlayed {
+            card: card.name.clone(),
+        };
+        let (counters, counter_events) = enemies
+            .inner
+            .iter()
+            .enumerate()
+            .filter(|(_, enemy)| enemy.is_standing())
+            .flat_map(|(idx, enemy)| {
+                enemy
+                    .player_play_card_effects
+                    .inner
+                    .iter()
+                    .map(move |eff| (enemy, eff.clone().aimed_at(idx).aimed_at_player(me, me)))
+            })
+            .fold(
+                (FpVec::new(), FpVec::new()),
+                |(effects, events), (enemy, eff)| match &eff.effect {
+                    EffectTrigger::Condition(cond, triggered_effect)
+                        if cond.check(
+                            &Situation::new(self, enemy, turn_number).playing(&card, cards_played),
+                        ) =>
+                    {
+                        let event = GameEvent::CounterEffect {
+                            target: eff.target.clone(),
+                            element: card.element.clone(),
+                            effect: triggered_effect.clone(),
+                        };
+                        let effect = GameEffect {
+                            name: eff.name.clone(),
+                            target: eff.target.clone(),
+                            effect: EffectTrigger::Always(triggered_effect.clone()),
+                        };
+                        (effects.push(effect), events.push(event))
+                    }
+                    _ => (effects, events),
+                },
+            );
+        let effects = FpVec::from_vec(
+            card.play_card_effects
                 .inner
-                .iter()
-                .fold(FpVec::new(), |effects, eff| {
-                    match &eff.effect {
-                        EffectTrigger::Condition(cond, triggered_effect)
-                        if *cond == EffectCondition::PlayerPlaysCardWithElement(card.element.clone()) => {
-                            if eff.target.is_player()
-                            {
-                                println!(
-                                    "Player causes counter effect to self due to casting spell of element {}: {}",
-                                    card.element.description(),
-                                    triggered_effect.description()
-                                );
-                                effects.push(GameEffect::player(
-                                    &eff.name,
-                                    EffectTrigger::Always(triggered_effect.clone()),
-                                ))
-                            } else {
-                                println!(
-                                    "Player causes counter effect on enemy due to casting spell of element {}: {}",
-                                    card.element.description(),
-                                    triggered_effect.description()
-                                );
-                                effects.push(GameEffect::enemy(
-                                    &eff.name,
-                                    EffectTrigger::Always(triggered_effect.clone()),
-                                ))
-                            }
-                        }
-                        _ => effects,
+                .into_iter()
+                .map(|eff| match eff.effect {
+                    EffectTrigger::Discard(_) => {
+                        GameEffect::player("Discard", EffectTrigger::Discard(card.id.clone()))
+                            .aimed_at_player(me, me)
                     }
-                });
-            FpVec::from_vec(
-                card.play_card_effects
-                    .inner
-                    .into_iter()
-                    .map(|eff| match eff.effect {
-                        EffectTrigger::Discard(_) => {
-                            GameEffect::player("Discard", EffectTrigger::Discard(card.id.clone()))
-                        }
-                        _ => eff,
-                    })
-                    .collect(),
-            )
-            .extend(enemy_thorns_effects)
-        }
+                    EffectTrigger::Exile(_) => {
+                        GameEffect::player("Exile", EffectTrigger::Exile(card.id.clone()))
+                            .aimed_at_player(me, me)
+                    }
+                    _ => eff.aimed_at(target).aimed_at_player(me, ally),
+                })
+                .collect(),
+        );
+        (
+            PlayedCard { effects, counters },
+            FpVec::from_vec(vec![played]).extend(counter_events),
+        )
     }
 
     pub fn start_turn(&self) -> FpVec<GameEffect> {
-        self.cards.inner.iter().fold(FpVec::new(), |effects, card| {
+        let draw_effects = if self.draw_per_turn > 0 {
+            FpVec::from_vec(vec![CardEffects::draw_cards(self.draw_per_turn)])
+        } else {
+            FpVec::new()
+        };
+        self.hand.inner.iter().fold(draw_effects, |effects, card| {
             effects.extend(card.start_turn_effects.clone())
         })
     }
 
     pub fn end_turn(&self) -> FpVec<GameEffect> {
-        self.current_activated_effects
+        self.enchantments()
             .inner
             .iter()
             .fold(FpVec::new(), |effects, eff| match eff {
@@ -132,101 +452,162 @@ impl Player {
             })
     }
 
-    pub fn trigger_effect(self, trigger: EffectTrigger, enemy: &Enemy) -> Self {
+    /// Resolves `trigger` on the player; a condition holds if it does against any enemy.
+    pub fn trigger_effect(
+        self,
+        trigger: EffectTrigger,
+        enemies: &FpVec<Enemy>,
+        rules: &DamageRules,
+        turn_number: u32,
+        rng: &mut GameRng,
+    ) -> (Self, FpVec<GameEvent>) {
         match trigger {
-            EffectTrigger::Always(effect) => self.apply_effect(effect),
-            EffectTrigger::Condition(cond, effect) => {
-                if cond.check_player(&self) && cond.check_enemy(enemy) {
-                    self.apply_effect(effect)
-                } else {
-                    self
-                }
+            EffectTrigger::Always(_) | EffectTrigger::Condition(_, _) => {
+                self.resolve_trigger(trigger, rules, |player, cond| {
+                    enemies
+                        .inner
+                        .iter()
+                        .any(|enemy| cond.check(&Situation::new(player, enemy, turn_number)))
+                })
             }
             EffectTrigger::Discard(id) => {
-                println!("Discarding {}", id);
-                Self {
-                    cards: FpVec::from_vec(
-                        self.cards
-                            .inner
-                            .into_iter()
-                            .filter(|card| card.id != id)
-                            .collect(),
-                    ),
-                    ..self
-                }
+                let event = GameEvent::CardDiscarded {
+                    card: self.card_name(&id),
+                };
+                (
+                    self.move_from_hand(&id, CardZone::Discard),
+                    FpVec::from_vec(vec![event]),
+                )
+            }
+            EffectTrigger::Exile(id) => {
+                let event = GameEvent::CardExiled {
+                    card: self.card_name(&id),
+                };
+                (
+                    self.move_from_hand(&id, CardZone::Exile),
+                    FpVec::from_vec(vec![event]),
+                )
             }
+            EffectTrigger::Draw(count) => self.draw_cards(count as usize, rng),
         }
     }
+}
 
-    fn apply_effect(self, effect: EffectType) -> Self {
-        match effect {
-            EffectType::Damage(dmg) => self.take_damage(dmg),
-            EffectType::LifeAdjust(amt) => {
-                println!("Player, {} HP", amt);
-                Self {
-                    hit_points: self.hit_points + amt,
-                    ..self
-                }
-            }
-            _ => Self { ..self },
+impl Combatant for Player {
+    const TARGET: EffectTarget = EffectTarget::Player;
+
+    fn vitals(&self) -> Vitals {
+        Vitals {
+            hit_points: self.hit_points,
+            current_activated_effects: self.current_activated_effects.clone(),
+            statuses: self.statuses.clone(),
         }
     }
 
-    fn take_damage(self, damage: Damage) -> Self {
-        let amount = self
-            .current_activated_effects
-            .inner
-            .iter()
-            .fold(damage.amount, |dmg, eff| match eff {
-                Enchantment::ShieldDamage(amt) => {
-                    if dmg - amt < 0 {
-                        0
-                    } else {
-                        dmg - amt
-                    }
-                }
-                _ => dmg,
-            });
+    fn with_vitals(self, vitals: Vitals) -> Self {
+        Self {
+            hit_points: vitals.hit_points,
+            current_activated_effects: vitals.current_activated_effects,
+            statuses: vitals.statuses,
+            ..self
+        }
+    }
 
-        println!(
-            "Player takes damage: {}/{}",
-            damage.element_type.description(),
-            amount
-        );
+    fn defense(&self) -> DefenseProps {
+        self.defense_props.clone()
+    }
+
+    fn pinned(target: EffectTarget, idx: usize) -> EffectTarget {
+        target.aimed_at_player(idx, idx)
+    }
+}
+
+/// A played card's own effects and the enemies' counters to it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlayedCard {
+    pub effects: FpVec<GameEffect>,
+    pub counters: FpVec<GameEffect>,
+}
+
+/// A card, the enemy to aim it at (default: first standing) and the ally to help.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CardPlay {
+    pub card: PlayerCard,
+    pub target: Option<usize>,
+    /// The player the card's ally effects go to, if not whoever plays it.
+    #[serde(default)]
+    pub ally: Option<usize>,
+}
+
+impl CardPlay {
+    pub fn new(card: PlayerCard) -> Self {
+        Self {
+            card,
+            target: None,
+            ally: None,
+        }
+    }
 
+    pub fn at(self, target: usize) -> Self {
         Self {
-            hit_points: self.hit_points - amount,
+            target: Some(target),
             ..self
         }
     }
+
+    pub fn for_ally(self, ally: usize) -> Self {
+        Self {
+            ally: Some(ally),
+            ..self
+        }
+    }
+
+    /// Plays each of `cards` at the default target.
+    pub fn untargeted(cards: FpVec<PlayerCard>) -> FpVec<Self> {
+        FpVec::from_vec(cards.inner.into_iter().map(Self::new).collect())
+    }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PlayerCard {
     pub id: String,
     pub element: ElementType,
+    pub cost: i32,
     pub can_play: bool,
     pub name: String,
     pub description: String,
     pub game_start_effects: FpVec<GameEffect>,
     pub start_turn_effects: FpVec<GameEffect>,
     pub play_card_effects: FpVec<GameEffect>,
+    /// Effects that resolve as a phase begins while the card is in hand.
+    #[serde(default)]
+    pub phase_effects: FpVec<PhaseHook>,
 }
 
 impl PlayerCard {
     pub fn new(name: &str, description: &str, element: ElementType) -> Self {
         Self {
-            id: Uuid::new_v4().to_hyphenated().to_string(),
+            id: Self::new_id(),
             name: name.to_string(),
             description: description.to_string(),
             element,
+            cost: 0,
             can_play: true,
             game_start_effects: FpVec::new(),
             start_turn_effects: FpVec::new(),
             play_card_effects: FpVec::new(),
+            phase_effects: FpVec::new(),
         }
     }
 
+    pub fn new_id() -> String {
+        Uuid::new_v4().to_hyphenated().to_string()
+    }
+
+    pub fn cost(self, cost: i32) -> Self {
+        Self { cost, ..self }
+    }
+
     pub fn cant_play(self) -> Self {
         Self {
             can_play: false,
@@ -254,6 +635,13 @@ impl PlayerCard {
             ..self
         }
     }
+
+    pub fn phase_effect(self, phase: Phase, effect: GameEffect) -> Self {
+        Self {
+            phase_effects: self.phase_effects.push(PhaseHook::new(phase, effect)),
+            ..self
+        }
+    }
 }
 
 pub struct SpecialCards;
@@ -266,6 +654,17 @@ impl SpecialCards {
         )
         .game_start_effect(Enchantments::player_shield_from_elem(2))
     }
+    pub fn dry_suit() -> PlayerCard {
+        PlayerCard::new(
+            "Dry Suit",
+            "If you have this card in your hand, take half damage from Water",
+            ElementType::Water,
+        )
+        .game_start_effect(Enchantments::player_elem_resistance(
+            ElementType::Water,
+            DamageAdjustment::Percent(0.5),
+        ))
+    }
     pub fn power_amp() -> PlayerCard {
         PlayerCard::new(
             "Power Amplifier",
@@ -383,6 +782,93 @@ impl SpecialCards {
         .play_card_effect(CardEffects::do_percent_damage(0.5))
         .play_card_effect(OnCardPlayEffects::discard_this_card())
     }
+    pub fn water_bomber() -> PlayerCard {
+        PlayerCard::new(
+            "Water Bomber",
+            "Deal 2 Water Damage to All Enemies",
+            ElementType::Water,
+        )
+        .play_card_effect(CardEffects::do_element_damage_to_all(ElementType::Water, 2))
+    }
+    pub fn fire_retardant() -> PlayerCard {
+        PlayerCard::new(
+            "Fire Retardant",
+            "Enemy takes 2 more Water Damage per hit for 2 Turns",
+            ElementType::Land,
+        )
+        .play_card_effect(CardEffects::afflict_enemy(Status::vulnerable(
+            ElementType::Water,
+            2,
+            2,
+        )))
+    }
+    pub fn relief_camp() -> PlayerCard {
+        PlayerCard::new(
+            "Relief Camp",
+            "An Ally Regenerates 2 Hit Points for 3 Turns",
+            ElementType::Land,
+        )
+        .play_card_effect(CardEffects::bless_ally(Status::regeneration(2, 3)))
+    }
+    pub fn sandbags() -> PlayerCard {
+        PlayerCard::new(
+            "Sandbags",
+            "Shield 1 Damage from every Attack for the rest of the Game",
+            ElementType::Land,
+        )
+        .play_card_effect(CardEffects::enchant(Enchantment::ShieldDamage(1)))
+    }
+    pub fn riptide() -> PlayerCard {
+        PlayerCard::new(
+            "Riptide",
+            "Play to cause 2 Water Damage, and 3 more if you play at least 3 cards this turn",
+            ElementType::Water,
+        )
+        .play_card_effect(CardEffects::do_element_damage(ElementType::Water, 2))
+        .play_card_effect(GameEffect::enemy(
+            "Riptide Surge",
+            EffectTrigger::Condition(
+                EffectCondition::Compare(Quantity::CardsPlayed, Comparison::AtLeast, 3),
+                EffectType::Damage(Damage::new(ElementType::Water, 3)),
+            ),
+        ))
+    }
+    pub fn clear_skies() -> PlayerCard {
+        PlayerCard::new(
+            "Clear Skies",
+            "Strip every Enchantment from an Enemy",
+            ElementType::Wind,
+        )
+        .play_card_effect(CardEffects::dispel_enemy())
+    }
+    pub fn field_medics() -> PlayerCard {
+        PlayerCard::new(
+            "Field Medics",
+            "Heal an Ally 6 Hit Points",
+            ElementType::Land,
+        )
+        .play_card_effect(CardEffects::heal_ally(6))
+    }
+    pub fn airlift() -> PlayerCard {
+        PlayerCard::new(
+            "Airlift",
+            "Discard this card and an Ally Draws 2 Cards",
+            ElementType::Wind,
+        )
+        .play_card_effect(CardEffects::ally_draw_cards(2))
+        .play_card_effect(OnCardPlayEffects::discard_this_card())
+    }
+    pub fn sea_wall() -> PlayerCard {
+        PlayerCard::new(
+            "Sea Wall",
+            "If you have this card in your hand, cause 1 Water Damage to each Enemy that attacks you as its turn ends",
+            ElementType::Water,
+        )
+        .phase_effect(
+            Phase::EnemyEnd,
+            CardEffects::do_element_damage(ElementType::Water, 1),
+        )
+    }
     pub fn tbd() -> PlayerCard {
         PlayerCard::new(
             "Time Slip",
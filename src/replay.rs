@@ -0,0 +1,253 @@
+use crate::damage::DamageRules;
+use crate::enemy::Enemy;
+use crate::events::GameEvent;
+use crate::fp_vec::FpVec;
+use crate::game::{Game, GameOutcome, TieRule};
+use crate::player::{CardPlay, Player};
+use serde::{Deserialize, Serialize};
+
+/// A card played, by id, and the enemy and ally it was aimed at.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedPlay {
+    pub card: String,
+    pub target: Option<usize>,
+    #[serde(default)]
+    pub ally: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReplayTurn {
+    /// The cards played, in order.
+    pub plays: Vec<RecordedPlay>,
+    /// Everything that happened during the turn when it was recorded.
+    pub events: FpVec<GameEvent>,
+    pub player_hit_points: Vec<i32>,
+    pub enemy_hit_points: Vec<i32>,
+}
+
+impl ReplayTurn {
+    /// The turn where `plays` were made and `game` was the result.
+    pub fn new(plays: &FpVec<CardPlay>, game: &Game) -> Self {
+        Self {
+            plays: plays
+                .inner
+                .iter()
+                .map(|play| RecordedPlay {
+                    card: play.card.id.clone(),
+                    target: play.target,
+                    ally: play.ally,
+                })
+                .collect(),
+            events: game.events.clone(),
+            player_hit_points: game
+                .players
+                .inner
+                .iter()
+                .map(|player| player.hit_points)
+                .collect(),
+            enemy_hit_points: game
+                .enemies
+                .inner
+                .iter()
+                .map(|enemy| enemy.hit_points)
+                .collect(),
+        }
+    }
+
+    pub fn description(&self) -> String {
+        format!(
+            "Player HP [{}] Enemy HP [{}]{}",
+            self.player_hit_points
+                .iter()
+                .map(|hp| hp.to_string())
+                .collect::<Vec<String>>()
+                .join(", "),
+            self.enemy_hit_points
+                .iter()
+                .map(|hp| hp.to_string())
+                .collect::<Vec<String>>()
+                .join(", "),
+            self.events
+                .inner
+                .iter()
+                .map(|event| format!("\n    {}", event.description()))
+                .collect::<Vec<String>>()
+                .join("")
+        )
+    }
+}
+
+/// A game's setup and every turn; the seed makes playing them back reproduce the game.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Replay {
+    #[serde(with = "crate::rng::as_i64")]
+    pub seed: u64,
+    pub enemies: FpVec<Enemy>,
+    pub players: FpVec<Player>,
+    #[serde(default)]
+    pub damage_rules: DamageRules,
+    #[serde(default)]
+    pub tie_rule: TieRule,
+    pub turns: FpVec<ReplayTurn>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Divergence {
+    CardNotInHand {
+        turn: u32,
+        card: String,
+    },
+    TurnDiffers {
+        turn: u32,
+        expected: Box<ReplayTurn>,
+        actual: Box<ReplayTurn>,
+    },
+    GameEnded {
+        turn: u32,
+        outcome: GameOutcome,
+    },
+}
+
+impl Divergence {
+    pub fn description(&self) -> String {
+        match self {
+            Divergence::CardNotInHand { turn, card } => {
+                format!("Turn {}: recorded card {} is not in hand", turn, card)
+            }
+            Divergence::TurnDiffers {
+                turn,
+                expected,
+                actual,
+            } => format!(
+                "Turn {}: result differs from the recording\n  Recorded: {}\n  Replayed: {}",
+                turn,
+                expected.description(),
+                actual.description()
+            ),
+            Divergence::GameEnded { turn, outcome } => format!(
+                "Turn {}: game is already over ({}) but more turns were recorded",
+                turn,
+                outcome.description()
+            ),
+        }
+    }
+}
+
+/// The games after each replayed turn, and where the replay diverged, if it did.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Playback {
+    pub games: Vec<Game>,
+    pub divergence: Option<Divergence>,
+}
+
+impl Replay {
+    pub fn new(enemy: Enemy, player: Player, seed: u64) -> Self {
+        Self::encounter(FpVec::from_vec(vec![enemy]), player, seed)
+    }
+
+    pub fn encounter(enemies: FpVec<Enemy>, player: Player, seed: u64) -> Self {
+        Self::coop(enemies, FpVec::from_vec(vec![player]), seed)
+    }
+
+    pub fn coop(enemies: FpVec<Enemy>, players: FpVec<Player>, seed: u64) -> Self {
+        Self {
+            seed,
+            enemies,
+            players,
+            damage_rules: DamageRules::default(),
+            tie_rule: TieRule::default(),
+            turns: FpVec::new(),
+        }
+    }
+
+    pub fn damage_rules(self, damage_rules: DamageRules) -> Self {
+        Self {
+            damage_rules,
+            ..self
+        }
+    }
+
+    pub fn tie_rule(self, tie_rule: TieRule) -> Self {
+        Self { tie_rule, ..self }
+    }
+
+    pub fn start(&self) -> Game {
+        Game::start_with_rules(
+            self.enemies.clone(),
+            self.players.clone(),
+            self.seed,
+            self.damage_rules.clone(),
+        )
+        .tie_rule(self.tie_rule.clone())
+    }
+
+    /// Adds a turn where `plays` were made and `game` was the result.
+    pub fn record(self, plays: &FpVec<CardPlay>, game: &Game) -> Self {
+        Self {
+            turns: self.turns.push(ReplayTurn::new(plays, game)),
+            ..self
+        }
+    }
+
+    fn replay_turn(game: &Game, turn: &ReplayTurn) -> Result<Game, Divergence> {
+        if game.game_result != GameOutcome::Undecided {
+            return Err(Divergence::GameEnded {
+                turn: game.turn_number,
+                outcome: game.game_result.clone(),
+            });
+        }
+        let plays = turn.plays.iter().try_fold(FpVec::new(), |plays, play| {
+            match game
+                .player()
+                .hand
+                .inner
+                .iter()
+                .find(|card| card.id == play.card)
+            {
+                Some(card) => Ok(plays.push(CardPlay {
+                    card: card.clone(),
+                    target: play.target,
+                    ally: play.ally,
+                })),
+                None => Err(Divergence::CardNotInHand {
+                    turn: game.turn_number,
+                    card: play.card.clone(),
+                }),
+            }
+        })?;
+        let next = game.clone().take_turn(plays.clone());
+        let actual = ReplayTurn::new(&plays, &next);
+        if actual == *turn {
+            Ok(next)
+        } else {
+            Err(Divergence::TurnDiffers {
+                turn: game.turn_number,
+                expected: Box::new(turn.clone()),
+                actual: Box::new(actual),
+            })
+        }
+    }
+
+    /// Plays the recorded turns back, stopping at the first that no longer matches.
+    pub fn play_back(&self) -> Playback {
+        let start = Playback {
+            games: vec![self.start()],
+            divergence: None,
+        };
+        self.turns.inner.iter().fold(start, |playback, turn| {
+            match (&playback.divergence, playback.games.last()) {
+                (None, Some(game)) => match Self::replay_turn(game, turn) {
+                    Ok(next) => Playback {
+                        games: playback.games.into_iter().chain(Some(next)).collect(),
+                        divergence: None,
+                    },
+                    Err(divergence) => Playback {
+                        divergence: Some(divergence),
+                        ..playback
+                    },
+                },
+                _ => playback,
+            }
+        })
+    }
+}
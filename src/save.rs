@@ -0,0 +1,70 @@
+use crate::game::Game;
+use crate::replay::Replay;
+use crate::versioned::{VersionError, VersionHeader, Versioned};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::fmt;
+use std::path::Path;
+
+#[derive(Debug)]
+pub enum SaveError {
+    Io(String, std::io::Error),
+    Format(serde_json::Error),
+    Version(VersionError),
+}
+
+impl fmt::Display for SaveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SaveError::Io(path, err) => write!(f, "cannot access file {}: {}", path, err),
+            SaveError::Format(err) => write!(f, "file is corrupt: {}", err),
+            SaveError::Version(err) => write!(f, "cannot load file: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for SaveError {}
+
+impl From<serde_json::Error> for SaveError {
+    fn from(err: serde_json::Error) -> Self {
+        SaveError::Format(err)
+    }
+}
+
+impl From<VersionError> for SaveError {
+    fn from(err: VersionError) -> Self {
+        SaveError::Version(err)
+    }
+}
+
+fn write_versioned<T: Serialize, P: AsRef<Path>>(value: &T, path: P) -> Result<(), SaveError> {
+    let text = serde_json::to_string_pretty(&Versioned::new(value))?;
+    std::fs::write(path.as_ref(), text)
+        .map_err(|err| SaveError::Io(path.as_ref().display().to_string(), err))
+}
+
+fn read_versioned<T: DeserializeOwned, P: AsRef<Path>>(path: P) -> Result<T, SaveError> {
+    let text = std::fs::read_to_string(path.as_ref())
+        .map_err(|err| SaveError::Io(path.as_ref().display().to_string(), err))?;
+    // Check the version first, so an old file reports it instead of a field error.
+    let header: VersionHeader = serde_json::from_str(&text)?;
+    header.check()?;
+    let saved: Versioned<T> = serde_json::from_str(&text)?;
+    Ok(saved.into_current()?)
+}
+
+pub fn save_game<P: AsRef<Path>>(game: &Game, path: P) -> Result<(), SaveError> {
+    write_versioned(game, path)
+}
+
+pub fn load_game<P: AsRef<Path>>(path: P) -> Result<Game, SaveError> {
+    read_versioned(path)
+}
+
+pub fn save_replay<P: AsRef<Path>>(replay: &Replay, path: P) -> Result<(), SaveError> {
+    write_versioned(replay, path)
+}
+
+pub fn load_replay<P: AsRef<Path>>(path: P) -> Result<Replay, SaveError> {
+    read_versioned(path)
+}
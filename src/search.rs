@@ -0,0 +1,343 @@
+use crate::combatant;
+use crate::fp_vec::FpVec;
+use crate::game::{Game, GameOutcome};
+use crate::player::{CardPlay, Player, PlayerCard};
+use crate::rng::GameRng;
+use crate::strategy::{helps_ally, PlayerStrategy};
+use crate::EffectTarget;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+const WIN_SCORE: f64 = 1_000_000.0;
+
+#[derive(Debug, Clone)]
+pub struct SearchConfig {
+    /// How many of the player's turns to look ahead.
+    pub depth: u32,
+    /// Stop after evaluating this many game states.
+    pub node_budget: u64,
+    pub time_limit: Option<Duration>,
+    /// How many guesses at the hidden library and dice each play is scored against.
+    pub samples: u32,
+}
+
+impl Default for SearchConfig {
+    fn default() -> Self {
+        Self {
+            depth: 2,
+            node_budget: 50_000,
+            time_limit: None,
+            samples: 4,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchResult {
+    pub cards: FpVec<CardPlay>,
+    pub score: f64,
+    /// The deepest look-ahead that finished within the budget.
+    pub depth: u32,
+    pub nodes: u64,
+}
+
+impl SearchResult {
+    pub fn description(&self) -> String {
+        format!(
+            "Play [{}] - score [{:.1}] depth [{}] nodes [{}]",
+            self.cards
+                .inner
+                .iter()
+                .map(|play| {
+                    let name = match play.target {
+                        Some(target) => format!("{} at enemy #{}", play.card.name, target + 1),
+                        None => play.card.name.clone(),
+                    };
+                    match play.ally {
+                        Some(ally) => format!("{} for player #{}", name, ally + 1),
+                        None => name,
+                    }
+                })
+                .collect::<Vec<String>>()
+                .join(", "),
+            self.score,
+            self.depth,
+            self.nodes
+        )
+    }
+}
+
+/// Wins beat everything, earlier wins beat later ones, draws are even and losses put off.
+pub fn evaluate(game: &Game) -> f64 {
+    match game.game_result {
+        GameOutcome::PlayerWins(turn) => {
+            WIN_SCORE - 1000.0 * turn as f64 + game.player_hit_points() as f64
+        }
+        GameOutcome::EnemyWins(turn) => -WIN_SCORE + turn as f64,
+        GameOutcome::Draw(_) => 0.0,
+        GameOutcome::Undecided => (game.player_hit_points() - game.enemy_hit_points()) as f64,
+    }
+}
+
+// Enemies worth aiming `card` at: all standing ones if it hits a single enemy.
+fn targets_for(game: &Game, card: &PlayerCard) -> Vec<Option<usize>> {
+    let single_target = card
+        .play_card_effects
+        .inner
+        .iter()
+        .any(|eff| eff.target == EffectTarget::Enemy);
+    match combatant::standing(&game.enemies) {
+        targets if single_target && targets.len() > 1 => targets.into_iter().map(Some).collect(),
+        _ => vec![None],
+    }
+}
+
+// Likewise for the players `card` could help.
+fn allies_for(game: &Game, card: &PlayerCard) -> Vec<Option<usize>> {
+    match combatant::standing(&game.players) {
+        allies if helps_ally(card) && allies.len() > 1 => allies.into_iter().map(Some).collect(),
+        _ => vec![None],
+    }
+}
+
+// One way to add a card: its group of copies, its cost and its aim.
+struct Choice {
+    group: usize,
+    cost: i32,
+    target: Option<usize>,
+    ally: Option<usize>,
+}
+
+struct Choices {
+    copies: Vec<Vec<PlayerCard>>,
+    choices: Vec<Choice>,
+}
+
+// Plays adding choices from `from` on; never going back keeps each set unique.
+fn extend_play(
+    choices: Rc<Choices>,
+    from: usize,
+    cards: FpVec<CardPlay>,
+    used: Vec<usize>,
+    power: i32,
+) -> Box<dyn Iterator<Item = FpVec<CardPlay>>> {
+    let this = std::iter::once(cards.clone());
+    let rest = (from..choices.choices.len()).flat_map(move |idx| {
+        let choice = &choices.choices[idx];
+        let copies = &choices.copies[choice.group];
+        match copies.get(used[choice.group]) {
+            Some(card) if choice.cost <= power => {
+                let play = CardPlay {
+                    card: card.clone(),
+                    target: choice.target,
+                    ally: choice.ally,
+                };
+                let mut used = used.clone();
+                used[choice.group] += 1;
+                let power = power - choice.cost;
+                extend_play(choices.clone(), idx, cards.clone().push(play), used, power)
+            }
+            _ => Box::new(std::iter::empty()),
+        }
+    });
+    Box::new(this.chain(rest))
+}
+
+/// Each distinct affordable set of cards and aims, built lazily; copies count as one.
+pub fn candidate_plays(game: &Game) -> impl Iterator<Item = FpVec<CardPlay>> {
+    let player = game.player();
+    let copies: Vec<Vec<PlayerCard>> = player
+        .hand
+        .inner
+        .iter()
+        .filter(|card| player.enchant_card((*card).clone()).can_play)
+        .fold(Vec::new(), |mut copies: Vec<Vec<PlayerCard>>, card| {
+            match copies.iter_mut().find(|group| group[0].name == card.name) {
+                Some(group) => group.push(card.clone()),
+                None => copies.push(vec![card.clone()]),
+            }
+            copies
+        });
+    let choices = copies
+        .iter()
+        .enumerate()
+        .flat_map(|(group, cards)| {
+            let card = &cards[0];
+            let cost = player.card_cost(card);
+            let allies = allies_for(game, card);
+            targets_for(game, card).into_iter().flat_map(move |target| {
+                allies.clone().into_iter().map(move |ally| Choice {
+                    group,
+                    cost,
+                    target,
+                    ally,
+                })
+            })
+        })
+        .collect();
+    let used = vec![0; copies.len()];
+    let choices = Rc::new(Choices { copies, choices });
+    extend_play(choices, 0, FpVec::new(), used, player.power)
+}
+
+// A finished pick, or the best play scored before the budget ran out.
+type Picked = Result<Option<(FpVec<CardPlay>, f64)>, Option<(FpVec<CardPlay>, f64)>>;
+
+struct Search {
+    node_budget: u64,
+    deadline: Option<Instant>,
+    nodes: u64,
+}
+
+impl Search {
+    fn exhausted(&self) -> bool {
+        self.nodes >= self.node_budget
+            || self
+                .deadline
+                .map(|deadline| Instant::now() >= deadline)
+                .unwrap_or(false)
+    }
+
+    // Returns None when the budget runs out before the subtree is finished.
+    fn value(&mut self, game: &Game, depth: u32) -> Option<f64> {
+        self.nodes += 1;
+        if depth == 0 || game.game_result != GameOutcome::Undecided {
+            return Some(evaluate(game));
+        }
+        self.best(game, depth).map(|(_, score)| score)
+    }
+
+    fn best(&mut self, game: &Game, depth: u32) -> Option<(FpVec<CardPlay>, f64)> {
+        self.pick(game, |search, cards| {
+            search.value(&game.clone().take_turn(cards.clone()), depth - 1)
+        })
+        .ok()
+        .flatten()
+    }
+
+    // Like `best`, but each play is scored by its average over `worlds`.
+    fn best_on_average(&mut self, worlds: &[Game], depth: u32) -> Picked {
+        self.pick(&worlds[0], |search, cards| {
+            worlds
+                .iter()
+                .try_fold(0.0, |total, world| {
+                    let score = search.value(&world.clone().take_turn(cards.clone()), depth - 1)?;
+                    Some(total + score)
+                })
+                .map(|total| total / worlds.len() as f64)
+        })
+    }
+
+    fn pick(
+        &mut self,
+        game: &Game,
+        score: impl Fn(&mut Self, &FpVec<CardPlay>) -> Option<f64>,
+    ) -> Picked {
+        candidate_plays(game).try_fold(None, |best: Option<(FpVec<CardPlay>, f64)>, cards| {
+            if self.exhausted() {
+                return Err(best);
+            }
+            match score(self, &cards) {
+                Some(score) => Ok(match best {
+                    Some((_, best_score)) if best_score >= score => best,
+                    _ => Some((cards, score)),
+                }),
+                None => Err(best),
+            }
+        })
+    }
+}
+
+// A guess at what the players can't see: shuffled libraries and reseeded dice.
+fn determinize<R: Rng>(game: &Game, rng: &mut R) -> Game {
+    let mut world_rng = GameRng::new(rng.gen());
+    let players = FpVec::from_vec(
+        game.players
+            .inner
+            .iter()
+            .map(|player| {
+                let mut library = player.library.inner.clone();
+                library.sort_by(|a, b| a.id.cmp(&b.id));
+                library.shuffle(&mut world_rng);
+                Player {
+                    library: FpVec::from_vec(library),
+                    ..player.clone()
+                }
+            })
+            .collect(),
+    );
+    Game {
+        players,
+        rng: world_rng,
+        ..game.clone()
+    }
+}
+
+/// Deepens until the budget runs out, scoring plays over guessed libraries and dice.
+pub fn best_play<R: Rng>(game: &Game, config: &SearchConfig, rng: &mut R) -> SearchResult {
+    let worlds: Vec<Game> = (0..config.samples.max(1))
+        .map(|_| determinize(game, rng))
+        .collect();
+    let mut search = Search {
+        node_budget: config.node_budget,
+        deadline: config.time_limit.map(|limit| Instant::now() + limit),
+        nodes: 0,
+    };
+    let fallback = SearchResult {
+        cards: FpVec::new(),
+        score: evaluate(game),
+        depth: 0,
+        nodes: 0,
+    };
+
+    (1..=config.depth.max(1))
+        .try_fold(fallback, |found, depth| {
+            match search.best_on_average(&worlds, depth) {
+                Ok(Some((cards, score))) => Ok(SearchResult {
+                    cards,
+                    score,
+                    depth,
+                    nodes: search.nodes,
+                }),
+                Err(Some((cards, score))) if found.depth == 0 => Err(SearchResult {
+                    cards,
+                    score,
+                    depth: 0,
+                    nodes: search.nodes,
+                }),
+                _ => Err(SearchResult {
+                    nodes: search.nodes,
+                    ..found
+                }),
+            }
+        })
+        .unwrap_or_else(|found| found)
+}
+
+/// Plays whatever `best_play` finds.
+pub struct SearchStrategy {
+    pub config: SearchConfig,
+    rng: StdRng,
+}
+
+impl SearchStrategy {
+    pub fn new(config: SearchConfig, seed: u64) -> Self {
+        Self {
+            config,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+}
+
+impl PlayerStrategy for SearchStrategy {
+    fn name(&self) -> String {
+        "search".to_string()
+    }
+
+    fn choose_cards(&mut self, game: &Game) -> FpVec<CardPlay> {
+        best_play(game, &self.config, &mut self.rng).cards
+    }
+}
@@ -0,0 +1,230 @@
+use crate::events::GameEvent;
+use crate::game::{Game, GameOutcome};
+use crate::strategy::PlayerStrategy;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone)]
+pub struct SimulationConfig {
+    pub games: u32,
+    pub seed: u64,
+    /// Games still undecided after this many turns are counted as unfinished.
+    pub max_turns: u32,
+}
+
+impl Default for SimulationConfig {
+    fn default() -> Self {
+        Self {
+            games: 1000,
+            seed: 0,
+            max_turns: 100,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GameRecord {
+    pub outcome: GameOutcome,
+    /// Across all players still standing.
+    pub player_hit_points: i32,
+    pub enemy_hit_points: i32,
+    pub card_plays: BTreeMap<String, u32>,
+}
+
+pub fn play_out(
+    game: Game,
+    strategy: &mut dyn PlayerStrategy,
+    max_turns: u32,
+) -> (Game, GameRecord) {
+    let mut game = game;
+    let mut card_plays = BTreeMap::new();
+    while game.game_result == GameOutcome::Undecided && game.turn_number <= max_turns {
+        let cards = strategy.choose_cards(&game);
+        game = game.take_turn(cards);
+        game.events.inner.iter().for_each(|event| {
+            if let GameEvent::CardPlayed { card } = event {
+                *card_plays.entry(card.clone()).or_insert(0) += 1;
+            }
+        });
+    }
+    let record = GameRecord {
+        outcome: game.game_result.clone(),
+        player_hit_points: game.player_hit_points(),
+        enemy_hit_points: game.enemy_hit_points(),
+        card_plays,
+    };
+    (game, record)
+}
+
+/// Runs `config.games` games; `setup` and `strategy` get per-game seeds drawn from `config.seed`.
+pub fn simulate<S, F>(config: &SimulationConfig, setup: S, strategy: F) -> SimulationReport
+where
+    S: Fn(&mut StdRng) -> Game,
+    F: Fn(u64) -> Box<dyn PlayerStrategy>,
+{
+    let mut rng = StdRng::seed_from_u64(config.seed);
+    let records = (0..config.games)
+        .map(|_| {
+            let game_seed: u64 = rng.gen();
+            let mut game_rng = StdRng::seed_from_u64(game_seed);
+            let game = setup(&mut game_rng);
+            let mut strategy = strategy(game_seed);
+            play_out(game, strategy.as_mut(), config.max_turns).1
+        })
+        .collect();
+    SimulationReport::from_records(records)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Distribution {
+    pub count: usize,
+    pub min: i32,
+    pub max: i32,
+    pub mean: f64,
+    pub median: f64,
+}
+
+impl Distribution {
+    pub fn from_values(values: &[i32]) -> Option<Self> {
+        if values.is_empty() {
+            return None;
+        }
+        let mut sorted = values.to_vec();
+        sorted.sort_unstable();
+        let count = sorted.len();
+        let median = if count.is_multiple_of(2) {
+            (sorted[count / 2 - 1] + sorted[count / 2]) as f64 / 2.0
+        } else {
+            sorted[count / 2] as f64
+        };
+        Some(Self {
+            count,
+            min: sorted[0],
+            max: sorted[count - 1],
+            mean: sorted.iter().map(|v| *v as f64).sum::<f64>() / count as f64,
+            median,
+        })
+    }
+
+    pub fn description(&self) -> String {
+        format!(
+            "mean {:.2}, median {:.1}, min {}, max {} (n={})",
+            self.mean, self.median, self.min, self.max, self.count
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimulationReport {
+    pub records: Vec<GameRecord>,
+}
+
+impl SimulationReport {
+    pub fn from_records(records: Vec<GameRecord>) -> Self {
+        Self { records }
+    }
+
+    pub fn games(&self) -> usize {
+        self.records.len()
+    }
+
+    pub fn wins(&self) -> usize {
+        self.count(|outcome| matches!(outcome, GameOutcome::PlayerWins(_)))
+    }
+
+    pub fn losses(&self) -> usize {
+        self.count(|outcome| matches!(outcome, GameOutcome::EnemyWins(_)))
+    }
+
+    pub fn draws(&self) -> usize {
+        self.count(|outcome| matches!(outcome, GameOutcome::Draw(_)))
+    }
+
+    pub fn unfinished(&self) -> usize {
+        self.count(|outcome| *outcome == GameOutcome::Undecided)
+    }
+
+    fn count<P: Fn(&GameOutcome) -> bool>(&self, pred: P) -> usize {
+        self.records
+            .iter()
+            .filter(|record| pred(&record.outcome))
+            .count()
+    }
+
+    pub fn win_rate(&self) -> f64 {
+        if self.records.is_empty() {
+            0.0
+        } else {
+            self.wins() as f64 / self.games() as f64
+        }
+    }
+
+    pub fn turns_to_win(&self) -> Option<Distribution> {
+        let turns: Vec<i32> = self
+            .records
+            .iter()
+            .filter_map(|record| match record.outcome {
+                GameOutcome::PlayerWins(turn) => Some(turn as i32),
+                _ => None,
+            })
+            .collect();
+        Distribution::from_values(&turns)
+    }
+
+    pub fn player_hit_points_on_win(&self) -> Option<Distribution> {
+        let hit_points: Vec<i32> = self
+            .records
+            .iter()
+            .filter(|record| matches!(record.outcome, GameOutcome::PlayerWins(_)))
+            .map(|record| record.player_hit_points)
+            .collect();
+        Distribution::from_values(&hit_points)
+    }
+
+    pub fn enemy_hit_points_on_loss(&self) -> Option<Distribution> {
+        let hit_points: Vec<i32> = self
+            .records
+            .iter()
+            .filter(|record| matches!(record.outcome, GameOutcome::EnemyWins(_)))
+            .map(|record| record.enemy_hit_points)
+            .collect();
+        Distribution::from_values(&hit_points)
+    }
+
+    /// Total plays of each card, by card name.
+    pub fn card_plays(&self) -> BTreeMap<String, u32> {
+        self.records
+            .iter()
+            .flat_map(|record| record.card_plays.iter())
+            .fold(BTreeMap::new(), |mut plays, (card, count)| {
+                *plays.entry(card.clone()).or_insert(0) += count;
+                plays
+            })
+    }
+
+    pub fn description(&self) -> String {
+        let describe = |dist: Option<Distribution>| {
+            dist.map(|d| d.description())
+                .unwrap_or_else(|| "n/a".to_string())
+        };
+        let games = self.games().max(1) as f64;
+        format!(
+            "Games [{}] Wins [{}] Losses [{}] Draws [{}] Unfinished [{}]\n  * Win rate [{:.1}%]\n  * Turns to win [{}]\n  * Player HP remaining on win [{}]\n  * Enemy HP remaining on loss [{}]\n  * Card plays per game [{}]",
+            self.games(),
+            self.wins(),
+            self.losses(),
+            self.draws(),
+            self.unfinished(),
+            self.win_rate() * 100.0,
+            describe(self.turns_to_win()),
+            describe(self.player_hit_points_on_win()),
+            describe(self.enemy_hit_points_on_loss()),
+            self.card_plays()
+                .iter()
+                .map(|(card, count)| format!("{}: {:.2}", card, *count as f64 / games))
+                .collect::<Vec<String>>()
+                .join(", "),
+        )
+    }
+}
@@ -0,0 +1,211 @@
+use crate::fp_vec::FpVec;
+use crate::{Damage, EffectType, ElementType, Enchantment};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum StatusKind {
+    /// Takes damage equal to the stacks each tick.
+    Burn,
+    /// Takes damage equal to the stacks each tick, losing a stack each time.
+    Poison,
+    /// Loses their turns while it lasts.
+    Stun,
+    /// Heals the stacks each tick.
+    Regeneration,
+    /// Takes extra damage equal to the stacks from attacks of the element.
+    Vulnerable(ElementType),
+    /// An enchantment that lasts as long as the status does.
+    Enchanted(Enchantment),
+}
+
+impl StatusKind {
+    pub fn description(&self) -> String {
+        match self {
+            StatusKind::Burn => "Burn".to_string(),
+            StatusKind::Poison => "Poison".to_string(),
+            StatusKind::Stun => "Stun".to_string(),
+            StatusKind::Regeneration => "Regeneration".to_string(),
+            StatusKind::Vulnerable(elem) => format!("Vulnerable to {}", elem.description()),
+            StatusKind::Enchanted(ench) => format!("Enchanted [{}]", ench.description()),
+        }
+    }
+}
+
+/// When a status takes effect and counts down: as its bearer's turn starts or ends.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Tick {
+    OnStart,
+    OnEnd,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Status {
+    pub kind: StatusKind,
+    pub stacks: i32,
+    /// Turns left before the status wears off.
+    pub turns: u32,
+    pub tick: Tick,
+}
+
+impl Status {
+    /// The usual tick for a kind: burns as the turn starts, everything else as it ends.
+    pub fn new(kind: StatusKind, stacks: i32, turns: u32) -> Self {
+        let tick = match kind {
+            StatusKind::Burn => Tick::OnStart,
+            _ => Tick::OnEnd,
+        };
+        Self {
+            kind,
+            stacks,
+            turns,
+            tick,
+        }
+    }
+
+    pub fn burn(stacks: i32, turns: u32) -> Self {
+        Self::new(StatusKind::Burn, stacks, turns)
+    }
+
+    pub fn poison(stacks: i32) -> Self {
+        Self::new(StatusKind::Poison, stacks, stacks.max(0) as u32)
+    }
+
+    pub fn stun(turns: u32) -> Self {
+        Self::new(StatusKind::Stun, 1, turns)
+    }
+
+    pub fn regeneration(stacks: i32, turns: u32) -> Self {
+        Self::new(StatusKind::Regeneration, stacks, turns)
+    }
+
+    pub fn vulnerable(element: ElementType, stacks: i32, turns: u32) -> Self {
+        Self::new(StatusKind::Vulnerable(element), stacks, turns)
+    }
+
+    pub fn enchanted(enchantment: Enchantment, turns: u32) -> Self {
+        Self::new(StatusKind::Enchanted(enchantment), 1, turns)
+    }
+
+    pub fn ticking(self, tick: Tick) -> Self {
+        Self { tick, ..self }
+    }
+
+    pub fn description(&self) -> String {
+        format!(
+            "{} x{} ({} turns)",
+            self.kind.description(),
+            self.stacks,
+            self.turns
+        )
+    }
+
+    // What the status does to its bearer when it ticks.
+    fn effect(&self) -> Option<EffectType> {
+        match self.kind {
+            StatusKind::Burn | StatusKind::Poison => {
+                Some(EffectType::Damage(Damage::raw(self.stacks)))
+            }
+            StatusKind::Regeneration => Some(EffectType::LifeAdjust(self.stacks)),
+            _ => None,
+        }
+    }
+
+    fn expired(&self) -> bool {
+        self.turns == 0 || self.stacks <= 0
+    }
+}
+
+/// Adds `status`, stacking onto one of its kind and keeping the longer duration.
+pub fn apply(statuses: FpVec<Status>, status: Status) -> FpVec<Status> {
+    if statuses.inner.iter().any(|held| held.kind == status.kind) {
+        FpVec::from_vec(
+            statuses
+                .inner
+                .into_iter()
+                .map(|held| match held {
+                    _ if held.kind == status.kind => Status {
+                        stacks: held.stacks + status.stacks,
+                        turns: held.turns.max(status.turns),
+                        ..held
+                    },
+                    held => held,
+                })
+                .collect(),
+        )
+    } else {
+        statuses.push(status)
+    }
+}
+
+/// Ticks `statuses`: what's left, the effects on the bearer and what wore off.
+pub fn tick(
+    statuses: FpVec<Status>,
+    tick: Tick,
+) -> (FpVec<Status>, FpVec<(String, EffectType)>, FpVec<Status>) {
+    statuses.inner.into_iter().fold(
+        (FpVec::new(), FpVec::new(), FpVec::new()),
+        |(kept, effects, expired), status| {
+            if status.tick != tick {
+                return (kept.push(status), effects, expired);
+            }
+            let effects = match status.effect() {
+                Some(effect) => effects.push((status.kind.description(), effect)),
+                None => effects,
+            };
+            let stacks = match status.kind {
+                StatusKind::Poison => status.stacks - 1,
+                _ => status.stacks,
+            };
+            let status = Status {
+                stacks,
+                turns: status.turns.saturating_sub(1),
+                ..status
+            };
+            if status.expired() {
+                (kept, effects, expired.push(status))
+            } else {
+                (kept.push(status), effects, expired)
+            }
+        },
+    )
+}
+
+pub fn is_stunned(statuses: &FpVec<Status>) -> bool {
+    statuses
+        .inner
+        .iter()
+        .any(|status| status.kind == StatusKind::Stun)
+}
+
+/// Extra damage from vulnerabilities to `element`.
+pub fn vulnerability(statuses: &FpVec<Status>, element: &ElementType) -> i32 {
+    statuses
+        .inner
+        .iter()
+        .filter(|status| status.kind == StatusKind::Vulnerable(element.clone()))
+        .map(|status| status.stacks)
+        .sum()
+}
+
+/// The enchantments granted by `statuses` for as long as they last.
+pub fn enchantments(statuses: &FpVec<Status>) -> FpVec<Enchantment> {
+    FpVec::from_vec(
+        statuses
+            .inner
+            .iter()
+            .filter_map(|status| match &status.kind {
+                StatusKind::Enchanted(ench) => Some(ench.clone()),
+                _ => None,
+            })
+            .collect(),
+    )
+}
+
+/// Splits off the statuses granting enchantments, returning those left and those stripped.
+pub fn dispel(statuses: FpVec<Status>) -> (FpVec<Status>, FpVec<Status>) {
+    let (stripped, kept) = statuses
+        .inner
+        .into_iter()
+        .partition(|status| matches!(status.kind, StatusKind::Enchanted(_)));
+    (FpVec::from_vec(kept), FpVec::from_vec(stripped))
+}
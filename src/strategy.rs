@@ -0,0 +1,304 @@
+use crate::combatant::{self, Combatant};
+use crate::condition::Situation;
+use crate::fp_vec::FpVec;
+use crate::game::Game;
+use crate::player::{CardPlay, PlayerCard};
+use crate::{Damage, EffectTarget, EffectTrigger, EffectType};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+pub trait PlayerStrategy {
+    fn name(&self) -> String;
+    fn choose_cards(&mut self, game: &Game) -> FpVec<CardPlay>;
+}
+
+/// Plays every card in hand, in hand order.
+pub struct PlayAllStrategy;
+
+impl PlayerStrategy for PlayAllStrategy {
+    fn name(&self) -> String {
+        "play_all".to_string()
+    }
+
+    fn choose_cards(&mut self, game: &Game) -> FpVec<CardPlay> {
+        CardPlay::untargeted(game.player().hand.clone())
+    }
+}
+
+/// Plays each card in hand with even odds, at a random enemy still standing.
+pub struct RandomStrategy {
+    rng: StdRng,
+}
+
+impl RandomStrategy {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+}
+
+impl PlayerStrategy for RandomStrategy {
+    fn name(&self) -> String {
+        "random".to_string()
+    }
+
+    fn choose_cards(&mut self, game: &Game) -> FpVec<CardPlay> {
+        let rng = &mut self.rng;
+        let targets = combatant::standing(&game.enemies);
+        FpVec::from_vec(
+            game.player()
+                .hand
+                .inner
+                .iter()
+                .filter_map(|card| match 0 {
+                    _ if !rng.gen_bool(0.5) => None,
+                    _ if targets.len() > 1 => targets
+                        .choose(rng)
+                        .map(|target| CardPlay::new(card.clone()).at(*target)),
+                    _ => Some(CardPlay::new(card.clone())),
+                })
+                .collect(),
+        )
+    }
+}
+
+fn damage_to(game: &Game, idx: usize, effect: &EffectTrigger) -> i32 {
+    let enemy = match game.enemies.inner.get(idx) {
+        Some(enemy) if enemy.is_standing() => enemy,
+        _ => return 0,
+    };
+    match effect {
+        EffectTrigger::Always(EffectType::Damage(dmg)) => {
+            enemy.adjusted_damage(dmg, &game.damage_rules)
+        }
+        EffectTrigger::Always(EffectType::PercentDamage(pct)) => enemy.adjusted_damage(
+            &Damage::raw(((enemy.hit_points as f64) * pct).floor() as i32),
+            &game.damage_rules,
+        ),
+        _ => 0,
+    }
+}
+
+/// Damage `card` would deal now at `target`, after enchantments and defenses.
+pub fn estimated_damage(game: &Game, card: &PlayerCard, target: usize) -> i32 {
+    let card = game.player().enchant_card(card.clone());
+    if !card.can_play {
+        return 0;
+    }
+    card.play_card_effects
+        .inner
+        .iter()
+        .map(|eff| match eff.target {
+            EffectTarget::Enemy => damage_to(game, target, &eff.effect),
+            EffectTarget::EnemyAt(idx) => damage_to(game, idx, &eff.effect),
+            EffectTarget::AllEnemies => combatant::standing(&game.enemies)
+                .into_iter()
+                .map(|idx| damage_to(game, idx, &eff.effect))
+                .sum(),
+            EffectTarget::Player
+            | EffectTarget::PlayerAt(_)
+            | EffectTarget::Ally
+            | EffectTarget::AllPlayers => 0,
+        })
+        .sum()
+}
+
+/// Whether `card` has an effect for an ally.
+pub fn helps_ally(card: &PlayerCard) -> bool {
+    card.play_card_effects
+        .inner
+        .iter()
+        .any(|eff| eff.target == EffectTarget::Ally)
+}
+
+/// The standing player with the fewest hit points.
+pub fn weakest_player(game: &Game) -> usize {
+    combatant::standing(&game.players)
+        .into_iter()
+        .min_by_key(|idx| game.players.inner[*idx].hit_points)
+        .unwrap_or(game.current_player)
+}
+
+/// The enemy `card` does the most damage to, and that damage.
+pub fn best_target(game: &Game, card: &PlayerCard) -> (usize, i32) {
+    combatant::standing(&game.enemies)
+        .into_iter()
+        .map(|idx| (idx, estimated_damage(game, card, idx)))
+        .fold(
+            (combatant::first_standing(&game.enemies), 0),
+            |best, (idx, damage)| {
+                if damage > best.1 {
+                    (idx, damage)
+                } else {
+                    best
+                }
+            },
+        )
+}
+
+/// Healing `card` would give the player if played now.
+pub fn estimated_healing(card: &PlayerCard) -> i32 {
+    card.play_card_effects
+        .inner
+        .iter()
+        .filter(|eff| eff.target.is_player())
+        .map(|eff| match &eff.effect {
+            EffectTrigger::Always(EffectType::LifeAdjust(amt)) => *amt,
+            _ => 0,
+        })
+        .sum()
+}
+
+/// Damage the player takes from the enemies' counters for playing `card`.
+pub fn estimated_backlash(game: &Game, card: &PlayerCard) -> i32 {
+    game.enemies
+        .inner
+        .iter()
+        .filter(|enemy| enemy.is_standing())
+        .flat_map(|enemy| {
+            enemy
+                .player_play_card_effects
+                .inner
+                .iter()
+                .map(move |eff| (enemy, eff))
+        })
+        .filter(|(_, eff)| eff.target.is_player())
+        .map(|(enemy, eff)| match &eff.effect {
+            EffectTrigger::Condition(cond, EffectType::Damage(dmg))
+                if cond.check(
+                    &Situation::new(game.player(), enemy, game.turn_number)
+                        .playing(card, game.player().cards_played + 1),
+                ) =>
+            {
+                dmg.amount
+            }
+            _ => 0,
+        })
+        .sum()
+}
+
+/// Damage the enemies' attacks and intended moves deal to the player next turn.
+pub fn incoming_damage(game: &Game) -> i32 {
+    game.enemies
+        .inner
+        .iter()
+        .filter(|enemy| enemy.is_standing())
+        .flat_map(|enemy| {
+            enemy
+                .start_turn_effects
+                .inner
+                .iter()
+                .chain(
+                    enemy
+                        .intended_move()
+                        .into_iter()
+                        .flat_map(|intent| intent.effects.inner.iter()),
+                )
+                .chain(enemy.end_turn_effects.inner.iter())
+        })
+        .filter(|eff| eff.target.is_player())
+        .map(|eff| match &eff.effect {
+            EffectTrigger::Always(EffectType::Damage(dmg)) => dmg.amount,
+            _ => 0,
+        })
+        .sum()
+}
+
+// The scored cards, best first, while affordable, each at its best enemy and ally.
+fn pick_affordable<K, F>(game: &Game, score: F) -> FpVec<CardPlay>
+where
+    K: Ord,
+    F: Fn(&PlayerCard) -> Option<K>,
+{
+    let player = game.player();
+    let mut scored: Vec<(K, &PlayerCard)> = player
+        .hand
+        .inner
+        .iter()
+        .filter_map(|card| score(card).map(|score| (score, card)))
+        .collect();
+    scored.sort_by(|a, b| b.0.cmp(&a.0));
+    scored
+        .into_iter()
+        .fold((FpVec::new(), player.power), |(cards, power), (_, card)| {
+            let cost = player.card_cost(card);
+            if cost <= power {
+                let (target, _) = best_target(game, card);
+                let play = CardPlay::new(card.clone()).at(target);
+                let play = match helps_ally(card) {
+                    true => play.for_ally(weakest_player(game)),
+                    false => play,
+                };
+                (cards.push(play), power - cost)
+            } else {
+                (cards, power)
+            }
+        })
+        .0
+}
+
+fn damage_score(game: &Game, card: &PlayerCard) -> Option<i32> {
+    Some(best_target(game, card).1).filter(|damage| *damage > 0)
+}
+
+/// Plays the cards that deal the most damage to the enemy.
+pub struct GreedyDamageStrategy;
+
+impl PlayerStrategy for GreedyDamageStrategy {
+    fn name(&self) -> String {
+        "greedy".to_string()
+    }
+
+    fn choose_cards(&mut self, game: &Game) -> FpVec<CardPlay> {
+        pick_affordable(game, |card| damage_score(game, card))
+    }
+}
+
+/// Greedy until hit points drop to `heal_below` (default: two attacks from death), then heals.
+pub struct ConservativeHealStrategy {
+    pub heal_below: Option<i32>,
+}
+
+impl ConservativeHealStrategy {
+    pub fn new() -> Self {
+        Self { heal_below: None }
+    }
+
+    pub fn heal_below(heal_below: i32) -> Self {
+        Self {
+            heal_below: Some(heal_below),
+        }
+    }
+}
+
+impl Default for ConservativeHealStrategy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PlayerStrategy for ConservativeHealStrategy {
+    fn name(&self) -> String {
+        "conservative".to_string()
+    }
+
+    fn choose_cards(&mut self, game: &Game) -> FpVec<CardPlay> {
+        let threshold = self.heal_below.unwrap_or_else(|| incoming_damage(game) * 2);
+        if game.player().hit_points > threshold {
+            pick_affordable(game, |card| damage_score(game, card))
+        } else {
+            // Healing outranks damage; cards with backlash are skipped.
+            pick_affordable(game, |card| {
+                let healing = estimated_healing(card);
+                let (_, damage) = best_target(game, card);
+                match 0 {
+                    _ if estimated_backlash(game, card) > 0 => None,
+                    _ if healing > 0 || damage > 0 => Some((healing, damage)),
+                    _ => None,
+                }
+            })
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Bump whenever a model change would alter how saved data deserializes.
+pub const FORMAT_VERSION: u32 = 17;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Versioned<T> {
+    pub format_version: u32,
+    pub data: T,
+}
+
+/// Just the version of a `Versioned` document, checked before the data itself.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VersionHeader {
+    pub format_version: u32,
+}
+
+impl VersionHeader {
+    pub fn check(&self) -> Result<(), VersionError> {
+        if self.format_version == FORMAT_VERSION {
+            Ok(())
+        } else {
+            Err(VersionError::Unsupported {
+                found: self.format_version,
+                expected: FORMAT_VERSION,
+            })
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum VersionError {
+    Unsupported { found: u32, expected: u32 },
+}
+
+impl fmt::Display for VersionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VersionError::Unsupported { found, expected } if found < expected => write!(
+                f,
+                "data was written with format version {}, which is older than the supported version {} and must be migrated",
+                found, expected
+            ),
+            VersionError::Unsupported { found, expected } => write!(
+                f,
+                "data was written with format version {}, which is newer than the supported version {}",
+                found, expected
+            ),
+        }
+    }
+}
+
+impl std::error::Error for VersionError {}
+
+impl<T> Versioned<T> {
+    pub fn new(data: T) -> Self {
+        Self {
+            format_version: FORMAT_VERSION,
+            data,
+        }
+    }
+
+    pub fn into_current(self) -> Result<T, VersionError> {
+        VersionHeader {
+            format_version: self.format_version,
+        }
+        .check()
+        .map(|_| self.data)
+    }
+}
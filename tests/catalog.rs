@@ -0,0 +1,109 @@
+use card_game_simulator::catalog::Catalog;
+use card_game_simulator::enemy::Enemy;
+use card_game_simulator::game_effects::CardEffects;
+use card_game_simulator::player::{PlayerCard, SpecialCards};
+use card_game_simulator::ElementType;
+
+// The first three cards only exist in the catalog.
+fn cards() -> Vec<(&'static str, PlayerCard)> {
+    vec![
+        (
+            "gust",
+            PlayerCard::new("Gust", "Play to cause 3 Wind Damage.", ElementType::Wind)
+                .play_card_effect(CardEffects::do_element_damage(ElementType::Wind, 3)),
+        ),
+        (
+            "stream",
+            PlayerCard::new("Stream", "Play to cause 3 Land Damage.", ElementType::Land)
+                .play_card_effect(CardEffects::do_element_damage(ElementType::Land, 3)),
+        ),
+        (
+            "first_aid",
+            PlayerCard::new("First Aid", "Play to head 8 hit points.", ElementType::Land)
+                .play_card_effect(CardEffects::heal(8)),
+        ),
+        ("env_suit", SpecialCards::env_suit()),
+        ("dry_suit", SpecialCards::dry_suit()),
+        ("power_amp", SpecialCards::power_amp()),
+        ("helis", SpecialCards::helis()),
+        ("hydro_power", SpecialCards::hydro_power()),
+        ("bulldozers", SpecialCards::bulldozers()),
+        ("wind_turbines", SpecialCards::wind_turbines()),
+        ("military_aid", SpecialCards::military_aid()),
+        ("time_slip", SpecialCards::time_slip()),
+        ("fire_breaks", SpecialCards::fire_breaks()),
+        ("fire_hose", SpecialCards::fire_hose()),
+        ("jet_blast", SpecialCards::jet_blast()),
+        ("logistics", SpecialCards::logistics()),
+        ("inside_help", SpecialCards::inside_help()),
+        ("water_bomber", SpecialCards::water_bomber()),
+        ("fire_retardant", SpecialCards::fire_retardant()),
+        ("relief_camp", SpecialCards::relief_camp()),
+        ("sandbags", SpecialCards::sandbags()),
+        ("riptide", SpecialCards::riptide()),
+        ("clear_skies", SpecialCards::clear_skies()),
+        ("field_medics", SpecialCards::field_medics()),
+        ("airlift", SpecialCards::airlift()),
+        ("sea_wall", SpecialCards::sea_wall()),
+        ("tbd", SpecialCards::tbd()),
+    ]
+}
+
+fn enemies() -> Vec<(&'static str, Enemy)> {
+    vec![
+        ("oil_spill", Enemy::oil_spill()),
+        ("typhoon", Enemy::typhoon()),
+        ("forest_fire", Enemy::forest_fire()),
+        ("landslide", Enemy::landslide()),
+        ("avalanche", Enemy::avalanche()),
+        ("famine", Enemy::famine()),
+        ("earthquake", Enemy::earthquake()),
+        ("volcano", Enemy::volcano()),
+        ("floods", Enemy::floods()),
+        ("drought", Enemy::drought()),
+        ("rising_floods", Enemy::rising_floods()),
+        ("scorching_drought", Enemy::scorching_drought()),
+        ("tornado", Enemy::tornado()),
+        ("meltdown", Enemy::meltdown()),
+        ("blackout", Enemy::blackout()),
+        ("chemical_leak", Enemy::chemical_leak()),
+        ("acid_rain", Enemy::acid_rain()),
+        ("sinkhole", Enemy::sinkhole()),
+        ("flash_flood", Enemy::flash_flood()),
+        ("thunderhead", Enemy::thunderhead()),
+        ("ember", Enemy::ember()),
+        ("wildfire", Enemy::wildfire()),
+        ("storm_cell", Enemy::storm_cell()),
+        ("hurricane", Enemy::hurricane()),
+    ]
+}
+
+#[test]
+fn every_catalog_card_matches_its_constructor() {
+    let catalog = Catalog::builtin();
+    let cards = cards();
+    assert_eq!(
+        catalog.card_keys(),
+        cards.iter().map(|(key, _)| *key).collect::<Vec<&str>>()
+    );
+    cards.into_iter().for_each(|(key, card)| {
+        let loaded = PlayerCard {
+            id: card.id.clone(),
+            ..catalog.card(key).unwrap()
+        };
+        assert_eq!(loaded, card, "card {}", key);
+    });
+}
+
+#[test]
+fn every_catalog_enemy_matches_its_constructor() {
+    let catalog = Catalog::builtin();
+    let enemies = enemies();
+    assert_eq!(
+        catalog.enemy_keys(),
+        enemies.iter().map(|(key, _)| *key).collect::<Vec<&str>>()
+    );
+    enemies.into_iter().for_each(|(key, enemy)| {
+        assert_eq!(catalog.enemy(key).unwrap(), enemy, "enemy {}", key);
+    });
+}
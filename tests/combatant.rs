@@ -0,0 +1,89 @@
+use card_game_simulator::combatant::{self, Combatant, Vitals};
+use card_game_simulator::damage::DamageRules;
+use card_game_simulator::events::GameEvent;
+use card_game_simulator::fp_vec::FpVec;
+use card_game_simulator::status::Status;
+use card_game_simulator::{
+    Damage, DamageAdjustment, DefenseProps, EffectTarget, EffectType, ElementType, Enchantment,
+};
+
+// A stand-in for a new kind of actor: only its vitals and defenses.
+#[derive(Debug, Clone, PartialEq)]
+struct Minion {
+    vitals: Vitals,
+}
+
+impl Combatant for Minion {
+    const TARGET: EffectTarget = EffectTarget::Enemy;
+
+    fn vitals(&self) -> Vitals {
+        self.vitals.clone()
+    }
+
+    fn with_vitals(self, vitals: Vitals) -> Self {
+        Self { vitals }
+    }
+
+    fn defense(&self) -> DefenseProps {
+        DefenseProps {
+            water: DamageAdjustment::Percent(0.5),
+            ..DefenseProps::normal()
+        }
+    }
+
+    fn pinned(target: EffectTarget, idx: usize) -> EffectTarget {
+        target.aimed_at(idx)
+    }
+}
+
+fn minion(hit_points: i32) -> Minion {
+    Minion {
+        vitals: Vitals {
+            hit_points,
+            current_activated_effects: FpVec::new(),
+            statuses: FpVec::new(),
+        },
+    }
+}
+
+#[test]
+fn new_combatants_get_the_shared_resolution() {
+    let rules = DamageRules::default();
+    let (minion, _) = minion(10).apply_effect(
+        EffectType::Enchantment(Enchantment::ShieldDamage(1)),
+        &rules,
+    );
+    let water = Damage::new(ElementType::Water, 6);
+    assert_eq!(minion.adjusted_damage(&water, &rules), 2);
+
+    let (minion, _) = minion.apply_effect(
+        EffectType::Status(Status::vulnerable(ElementType::Water, 2, 1)),
+        &rules,
+    );
+    let (minion, _) = minion.apply_effect(EffectType::Damage(water), &rules);
+    assert_eq!(minion.vitals.hit_points, 6);
+
+    let (minion, _) = minion.apply_effect(EffectType::PercentDamage(0.5), &rules);
+    assert_eq!(minion.vitals.hit_points, 4);
+    assert!(minion.is_standing());
+}
+
+#[test]
+fn updates_pin_events_to_the_combatant() {
+    let rules = DamageRules::default();
+    let minions = FpVec::from_vec(vec![minion(0), minion(5), minion(5)]);
+    assert_eq!(combatant::standing(&minions), vec![1, 2]);
+    assert_eq!(combatant::first_standing(&minions), 1);
+
+    let (minions, events) = combatant::update_at(minions, 2, |minion| {
+        minion.apply_effect(EffectType::LifeAdjust(3), &rules)
+    });
+    assert_eq!(minions.inner[2].vitals.hit_points, 8);
+    assert_eq!(
+        events.inner,
+        vec![GameEvent::LifeAdjusted {
+            target: EffectTarget::EnemyAt(2),
+            amount: 3,
+        }]
+    );
+}
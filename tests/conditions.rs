@@ -0,0 +1,118 @@
+use card_game_simulator::catalog::Catalog;
+use card_game_simulator::combatant::Combatant;
+use card_game_simulator::enemy::Enemy;
+use card_game_simulator::fp_vec::FpVec;
+use card_game_simulator::game::Game;
+use card_game_simulator::game_effects::{CardEffects, GameEffect};
+use card_game_simulator::player::{CardPlay, Player, PlayerCard};
+use card_game_simulator::{
+    Damage, EffectCondition, EffectTrigger, EffectType, ElementType, Enchantment,
+};
+
+fn card(effect: GameEffect) -> PlayerCard {
+    PlayerCard::new("Test", "", ElementType::NoElement).play_card_effect(effect)
+}
+
+fn solo(cards: Vec<PlayerCard>, enemy: Enemy) -> Game {
+    Game::start_seeded(enemy, Player::new(30, FpVec::from_vec(cards)), 1)
+}
+
+fn play_all(game: Game) -> Game {
+    let cards = CardPlay::untargeted(game.player().hand.clone());
+    game.take_turn(cards)
+}
+
+fn when(cond: EffectCondition, effect: EffectType) -> EffectTrigger {
+    EffectTrigger::Condition(cond, effect)
+}
+
+#[test]
+fn enemies_enrage_below_a_share_of_their_hit_points() {
+    assert_eq!(
+        Catalog::builtin().enemy("thunderhead").unwrap(),
+        Enemy::thunderhead()
+    );
+    let game = solo(vec![], Enemy::thunderhead()).take_turn(FpVec::new());
+    assert_eq!(game.player().hit_points, 28);
+
+    let wounded = Enemy {
+        hit_points: 7,
+        ..Enemy::thunderhead()
+    };
+    let game = solo(vec![], wounded).take_turn(FpVec::new());
+    assert_eq!(game.player().hit_points, 25);
+}
+
+#[test]
+fn enemies_counter_the_elements_they_took_this_round() {
+    let water = card(CardEffects::do_element_damage(ElementType::Water, 3));
+    let game = solo(vec![water], Enemy::thunderhead());
+    let cards = CardPlay::untargeted(game.player().hand.clone());
+    let game = game.take_player_turn(cards);
+    assert_eq!(
+        game.enemies.inner[0].damage_taken.inner,
+        vec![ElementType::Water]
+    );
+    let game = game.take_enemy_turn();
+    assert_eq!(game.player().hit_points, 26);
+
+    // The round is over, so the water is forgotten.
+    let game = game.take_turn(FpVec::new());
+    assert_eq!(game.player().hit_points, 24);
+}
+
+#[test]
+fn enemies_recover_after_a_skipped_turn() {
+    let enemy = Enemy::landslide().start_turn_effect(GameEffect::enemy(
+        "Second Wind",
+        when(
+            EffectCondition::EnemySkippedLastTurn,
+            EffectType::LifeAdjust(5),
+        ),
+    ));
+    let game = play_all(solo(vec![card(CardEffects::skip_enemy_turn())], enemy));
+    assert!(game.enemies.inner[0].skipped_last_turn);
+    assert_eq!(game.enemies.inner[0].hit_points, 20);
+
+    let game = game.take_turn(FpVec::new());
+    assert!(!game.enemies.inner[0].skipped_last_turn);
+    assert_eq!(game.enemies.inner[0].hit_points, 25);
+    let game = game.take_turn(FpVec::new());
+    assert_eq!(game.enemies.inner[0].hit_points, 25);
+}
+
+#[test]
+fn enemy_enchantments_check_the_enemy() {
+    let cornered = GameEffect::enemy(
+        "Cornered",
+        when(
+            EffectCondition::EnemyHitPointsBelow(10),
+            EffectType::Enchantment(Enchantment::ShieldDamage(2)),
+        ),
+    );
+    let enemy = Enemy::landslide().enchantment(cornered);
+    let game = solo(vec![], enemy.clone());
+    assert!(game.enemies.inner[0].enchantments().inner.is_empty());
+    let cornered = Enemy {
+        hit_points: 5,
+        ..enemy
+    };
+    let game = solo(vec![], cornered.clone());
+    assert_eq!(
+        game.enemies.inner[0].enchantments().inner,
+        vec![Enchantment::ShieldDamage(2)]
+    );
+
+    // Effects on the player can look at the enemy too.
+    let spiked = cornered
+        .end_turn_effects_cleared()
+        .end_turn_effect(GameEffect::player(
+            "Spikes",
+            when(
+                EffectCondition::EnemyHasEnchantment(Enchantment::ShieldDamage(2)),
+                EffectType::Damage(Damage::raw(1)),
+            ),
+        ));
+    let game = solo(vec![], spiked).take_turn(FpVec::new());
+    assert_eq!(game.player().hit_points, 29);
+}
@@ -0,0 +1,115 @@
+use card_game_simulator::catalog::Catalog;
+use card_game_simulator::enemy::{Enemy, PlayerTargeting};
+use card_game_simulator::events::{BlockReason, GameEvent};
+use card_game_simulator::fp_vec::FpVec;
+use card_game_simulator::game::{Game, GameOutcome};
+use card_game_simulator::player::{CardPlay, Player};
+
+fn coop(hit_points: &[i32], cards: &[&str], enemy: Enemy) -> Game {
+    let catalog = Catalog::builtin();
+    let players = hit_points
+        .iter()
+        .map(|hp| Player::new(*hp, catalog.cards(cards).unwrap()))
+        .collect();
+    Game::start_coop(FpVec::from_vec(vec![enemy]), FpVec::from_vec(players), 1)
+}
+
+fn hit_points(game: &Game) -> Vec<i32> {
+    game.players
+        .inner
+        .iter()
+        .map(|player| player.hit_points)
+        .collect()
+}
+
+fn first_card(game: &Game) -> CardPlay {
+    CardPlay::new(game.player().hand.inner[0].clone())
+}
+
+#[test]
+fn every_player_acts_before_the_enemy() {
+    let game = coop(&[20, 20], &["stream"], Enemy::landslide());
+    assert_eq!(game.current_player, 0);
+
+    let game = game.take_turn(FpVec::new());
+    assert_eq!(game.current_player, 1);
+    assert_eq!(game.turn_number, 1);
+    assert_eq!(hit_points(&game), vec![20, 20]);
+
+    let game = game.take_turn(FpVec::new());
+    assert_eq!(game.current_player, 0);
+    assert_eq!(game.turn_number, 2);
+    assert_eq!(hit_points(&game).iter().sum::<i32>(), 38);
+}
+
+#[test]
+fn enemies_attack_the_player_their_targeting_picks() {
+    let enemy = Enemy::landslide().targeting(PlayerTargeting::Weakest);
+    let game = coop(&[20, 12], &["stream"], enemy);
+    let game = game.take_turn(FpVec::new()).take_turn(FpVec::new());
+    assert_eq!(hit_points(&game), vec![20, 10]);
+
+    let enemy = Enemy::landslide().targeting(PlayerTargeting::First);
+    let game = coop(&[20, 12], &["stream"], enemy);
+    let game = game.take_turn(FpVec::new()).take_turn(FpVec::new());
+    assert_eq!(hit_points(&game), vec![18, 12]);
+}
+
+#[test]
+fn ally_cards_help_the_chosen_player() {
+    let game = coop(&[20, 12], &["field_medics"], Enemy::landslide());
+    let plays = FpVec::from_vec(vec![first_card(&game).for_ally(1)]);
+    let game = game.take_turn(plays);
+    assert_eq!(hit_points(&game), vec![20, 18]);
+}
+
+#[test]
+fn ally_cards_default_to_the_player_who_plays_them() {
+    let game = coop(&[12, 12], &["field_medics"], Enemy::landslide());
+    let plays = FpVec::from_vec(vec![first_card(&game)]);
+    let game = game.take_turn(plays);
+    assert_eq!(hit_points(&game), vec![18, 12]);
+}
+
+#[test]
+fn a_downed_ally_stays_down() {
+    let game = coop(&[20, 12], &["field_medics"], Enemy::landslide());
+    let game = Game {
+        players: FpVec::from_vec(vec![
+            game.players.inner[0].clone(),
+            Player {
+                hit_points: 0,
+                ..game.players.inner[1].clone()
+            },
+        ]),
+        ..game
+    };
+    let medics = first_card(&game);
+    let plays = FpVec::from_vec(vec![medics.clone().for_ally(1), medics.for_ally(2)]);
+    let game = game.take_player_turn(plays);
+
+    assert_eq!(hit_points(&game), vec![20, 0]);
+    let blocked = GameEvent::CardBlocked {
+        card: "Field Medics".to_string(),
+        reason: BlockReason::AllyDown,
+    };
+    assert_eq!(game.events.inner, vec![blocked.clone(), blocked]);
+}
+
+#[test]
+fn defeated_players_are_skipped_until_everyone_is_down() {
+    let enemy = Enemy::landslide().targeting(PlayerTargeting::Weakest);
+    let game = coop(&[20, 2], &["stream"], enemy);
+    let game = game.take_turn(FpVec::new()).take_turn(FpVec::new());
+    assert_eq!(hit_points(&game), vec![20, 0]);
+    assert_eq!(game.game_result, GameOutcome::Undecided);
+
+    // Player #2 is down, so player #1's turn ends the round.
+    assert_eq!(game.current_player, 0);
+    let game = game.take_turn(FpVec::new());
+    assert_eq!(game.current_player, 0);
+    assert_eq!(hit_points(&game), vec![18, 0]);
+
+    let game = (0..9).fold(game, |game, _| game.take_turn(FpVec::new()));
+    assert_eq!(game.game_result, GameOutcome::EnemyWins(11));
+}
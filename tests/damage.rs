@@ -0,0 +1,97 @@
+use card_game_simulator::damage::{Composition, DamageRules, DamageStep};
+use card_game_simulator::enemy::Enemy;
+use card_game_simulator::events::GameEvent;
+use card_game_simulator::fp_vec::FpVec;
+use card_game_simulator::game::Game;
+use card_game_simulator::player::Player;
+use card_game_simulator::{
+    Damage, DamageAdjustment, DefenseProps, EffectTarget, ElementType, Enchantment,
+};
+
+fn water(amount: i32) -> Damage {
+    Damage::new(ElementType::Water, amount)
+}
+
+#[test]
+fn steps_apply_in_order_and_are_traced() {
+    let damage = Damage {
+        bonus: 2,
+        ..water(6)
+    };
+    let defense = DefenseProps {
+        any: DamageAdjustment::Absolute(1),
+        water: DamageAdjustment::Percent(0.5),
+        ..DefenseProps::normal()
+    };
+    let shield = FpVec::from_vec(vec![Enchantment::ShieldDamage(1)]);
+    let (amount, trace) = DamageRules::default().resolve(&damage, &defense, &shield, &FpVec::new());
+    assert_eq!(amount, 4);
+    assert_eq!(
+        trace,
+        vec![
+            (DamageStep::AttackerBonus, 8),
+            (DamageStep::ElementMultiplier, 4),
+            (DamageStep::FlatResistance, 5),
+            (DamageStep::Shield, 4),
+        ]
+    );
+
+    let shields_first = DamageRules::default().steps(vec![
+        DamageStep::Shield,
+        DamageStep::ElementMultiplier,
+        DamageStep::Floor,
+    ]);
+    let shield = FpVec::from_vec(vec![Enchantment::ShieldDamage(2)]);
+    let (amount, _) = shields_first.resolve(&water(6), &defense, &shield, &FpVec::new());
+    assert_eq!(amount, 2);
+}
+
+#[test]
+fn damage_never_goes_below_zero() {
+    let shield = FpVec::from_vec(vec![Enchantment::ShieldDamage(3)]);
+    let (amount, trace) =
+        DamageRules::default().resolve(&water(1), &DefenseProps::normal(), &shield, &FpVec::new());
+    assert_eq!(amount, 0);
+    assert_eq!(
+        trace,
+        vec![(DamageStep::Shield, -2), (DamageStep::Floor, 0)]
+    );
+}
+
+#[test]
+fn composition_decides_how_adjustments_combine() {
+    let defense = DefenseProps {
+        any: DamageAdjustment::Absolute(-1),
+        water: DamageAdjustment::Absolute(-3),
+        ..DefenseProps::normal()
+    };
+    let stacked = DamageRules::default();
+    let lowest = DamageRules::default().composition(Composition::Lowest);
+    assert_eq!(
+        stacked
+            .resolve(&water(6), &defense, &FpVec::new(), &FpVec::new())
+            .0,
+        2
+    );
+    assert_eq!(
+        lowest
+            .resolve(&water(6), &defense, &FpVec::new(), &FpVec::new())
+            .0,
+        3
+    );
+}
+
+#[test]
+fn weaknesses_add_to_elemental_attacks() {
+    let weak = Player::new(30, FpVec::new()).defense_props(DefenseProps {
+        water: DamageAdjustment::Absolute(2),
+        ..DefenseProps::normal()
+    });
+    let game = Game::start_seeded(Enemy::flash_flood(), weak, 1).take_turn(FpVec::new());
+    assert_eq!(game.player().hit_points, 24);
+    assert!(game.events.inner.contains(&GameEvent::DamageAdjusted {
+        target: EffectTarget::PlayerAt(0),
+        step: DamageStep::FlatResistance,
+        amount: 6,
+    }));
+}
@@ -0,0 +1,55 @@
+use card_game_simulator::catalog::Catalog;
+use card_game_simulator::fp_vec::FpVec;
+use card_game_simulator::game::Game;
+use card_game_simulator::player::{CardPlay, Player};
+
+fn start(seed: u64) -> Game {
+    let catalog = Catalog::builtin();
+    // A small hand and a draw each turn, so the discard pile gets reshuffled.
+    let player = Player::new(
+        30,
+        catalog
+            .cards(&["gust", "stream", "time_slip", "first_aid", "fire_hose"])
+            .unwrap(),
+    )
+    .starting_hand(2)
+    .draws_per_turn(1);
+    Game::start_seeded(catalog.enemy("landslide").unwrap(), player, seed)
+}
+
+// Plays the first card in hand each turn, which discards them over time.
+fn play(game: Game, turns: u32) -> Game {
+    (0..turns).fold(game, |game, _| {
+        let cards = FpVec::from_vec(game.player().hand.inner.iter().take(1).cloned().collect());
+        let cards = CardPlay::untargeted(cards);
+        game.take_player_turn(cards).take_enemy_turn()
+    })
+}
+
+#[test]
+fn same_seed_and_plays_give_the_same_game() {
+    assert_eq!(play(start(7), 8), play(start(7), 8));
+}
+
+#[test]
+fn card_ids_come_from_the_seed() {
+    let ids = |game: &Game| -> Vec<String> {
+        game.player()
+            .library
+            .inner
+            .iter()
+            .chain(game.player().hand.inner.iter())
+            .map(|card| card.id.clone())
+            .collect()
+    };
+    assert_eq!(ids(&start(7)), ids(&start(7)));
+    assert_ne!(ids(&start(7)), ids(&start(8)));
+}
+
+#[test]
+fn loaded_game_continues_with_the_same_randomness() {
+    let game = play(start(11), 3);
+    let text = serde_json::to_string(&game).unwrap();
+    let loaded: Game = serde_json::from_str(&text).unwrap();
+    assert_eq!(play(loaded, 5), play(game, 5));
+}
@@ -0,0 +1,101 @@
+use card_game_simulator::catalog::Catalog;
+use card_game_simulator::combatant::Combatant;
+use card_game_simulator::enemy::Enemy;
+use card_game_simulator::events::GameEvent;
+use card_game_simulator::fp_vec::FpVec;
+use card_game_simulator::game::Game;
+use card_game_simulator::game_effects::GameEffect;
+use card_game_simulator::player::{CardPlay, Player};
+use card_game_simulator::status::Status;
+use card_game_simulator::{EffectTarget, EffectTrigger, EffectType, Enchantment};
+
+fn solo(cards: &[&str], enemy: Enemy) -> Game {
+    let cards = Catalog::builtin().cards(cards).unwrap();
+    Game::start_seeded(enemy, Player::new(30, cards), 1)
+}
+
+fn play_all(game: Game) -> Game {
+    let cards = CardPlay::untargeted(game.player().hand.clone());
+    game.take_turn(cards)
+}
+
+fn enemy_effect(effect: EffectType) -> GameEffect {
+    GameEffect::player("Test", EffectTrigger::Always(effect))
+}
+
+#[test]
+fn cards_grant_enchantments_mid_game() {
+    let game = play_all(solo(&["sandbags"], Enemy::landslide()));
+    assert_eq!(
+        game.player().current_activated_effects.inner,
+        vec![Enchantment::ShieldDamage(1)]
+    );
+    assert_eq!(game.player().hit_points, 29);
+    assert!(game
+        .events
+        .inner
+        .contains(&GameEvent::EnchantmentActivated {
+            target: EffectTarget::PlayerAt(0),
+            enchantment: Enchantment::ShieldDamage(1),
+        }));
+}
+
+#[test]
+fn enemy_moves_dispel_the_players_enchantments() {
+    let game = play_all(solo(&["sandbags"], Enemy::acid_rain()));
+    assert_eq!(game.player().hit_points, 29);
+
+    let game = game.take_turn(FpVec::new());
+    assert!(game.player().current_activated_effects.inner.is_empty());
+    assert_eq!(game.player().hit_points, 27);
+    assert!(game.events.inner.contains(&GameEvent::EnchantmentRemoved {
+        target: EffectTarget::PlayerAt(0),
+        enchantment: Enchantment::ShieldDamage(1),
+    }));
+}
+
+#[test]
+fn enchantments_can_be_replaced_or_removed() {
+    let enemy = Enemy::landslide()
+        .end_turn_effects_cleared()
+        .end_turn_effect(enemy_effect(EffectType::ReplaceEnchantment(
+            Enchantment::ShieldDamage(1),
+            Enchantment::ShieldDamage(3),
+        )))
+        .end_turn_effect(enemy_effect(EffectType::RemoveEnchantment(
+            Enchantment::LifeAdjPerTurn(1),
+        )));
+    let game = play_all(solo(&["sandbags"], enemy));
+    assert_eq!(
+        game.player().current_activated_effects.inner,
+        vec![Enchantment::ShieldDamage(3)]
+    );
+    assert!(game.events.inner.contains(&GameEvent::EnchantmentRemoved {
+        target: EffectTarget::PlayerAt(0),
+        enchantment: Enchantment::ShieldDamage(1),
+    }));
+}
+
+#[test]
+fn dispel_strips_enchantments_granted_by_statuses() {
+    let enemy = Enemy::landslide()
+        .end_turn_effects_cleared()
+        .start_turn_effect(GameEffect::enemy(
+            "Harden",
+            EffectTrigger::Always(EffectType::Status(Status::enchanted(
+                Enchantment::ShieldDamage(2),
+                3,
+            ))),
+        ));
+    let game = solo(&["clear_skies"], enemy).take_turn(FpVec::new());
+    assert_eq!(game.enemies.inner[0].enchantments().inner.len(), 1);
+
+    let game = play_all(game);
+    assert!(game.events.inner.iter().any(|event| matches!(
+        event,
+        GameEvent::StatusExpired {
+            target: EffectTarget::EnemyAt(0),
+            ..
+        }
+    )));
+}
@@ -0,0 +1,191 @@
+use card_game_simulator::catalog::Catalog;
+use card_game_simulator::enemy::Enemy;
+use card_game_simulator::events::{BlockReason, GameEvent};
+use card_game_simulator::fp_vec::FpVec;
+use card_game_simulator::game::{Game, GameOutcome};
+use card_game_simulator::game_effects::OnCardPlayEffects;
+use card_game_simulator::player::{CardPlay, Player};
+use card_game_simulator::{DamageAdjustment, DefenseProps, EffectTarget, ElementType};
+
+fn encounter(cards: &[&str]) -> Game {
+    let catalog = Catalog::builtin();
+    let player = Player::new(30, catalog.cards(cards).unwrap());
+    let enemies = catalog.encounter(&["typhoon", "floods"]).unwrap();
+    Game::start_encounter(enemies, player, 1)
+}
+
+fn hit_points(game: &Game) -> Vec<i32> {
+    game.enemies
+        .inner
+        .iter()
+        .map(|enemy| enemy.hit_points)
+        .collect()
+}
+
+fn play(game: &Game, card: usize, target: Option<usize>) -> FpVec<CardPlay> {
+    let play = CardPlay::new(game.player().hand.inner[card].clone());
+    FpVec::from_vec(vec![match target {
+        Some(target) => play.at(target),
+        None => play,
+    }])
+}
+
+#[test]
+fn cards_hit_the_chosen_enemy() {
+    let game = encounter(&["stream"]);
+    let start = hit_points(&game);
+    let plays = play(&game, 0, Some(1));
+    let game = game.take_player_turn(plays);
+    assert_eq!(hit_points(&game)[0], start[0]);
+    assert!(hit_points(&game)[1] < start[1]);
+}
+
+#[test]
+fn untargeted_cards_hit_the_first_enemy_standing() {
+    let game = encounter(&["stream"]);
+    let start = hit_points(&game);
+    let plays = play(&game, 0, None);
+    let game = game.take_player_turn(plays);
+    assert!(hit_points(&game)[0] < start[0]);
+    assert_eq!(hit_points(&game)[1], start[1]);
+}
+
+#[test]
+fn area_damage_hits_every_enemy() {
+    let game = encounter(&["water_bomber"]);
+    let start = hit_points(&game);
+    let plays = play(&game, 0, None);
+    let game = game.take_player_turn(plays);
+    assert!(hit_points(&game)
+        .iter()
+        .zip(start.iter())
+        .all(|(now, before)| now < before));
+}
+
+#[test]
+fn every_enemy_attacks_and_all_must_fall() {
+    let game = encounter(&["stream"]);
+    let game = game.take_turn(FpVec::new());
+    // Typhoon hits for 4 and Floods for 5.
+    assert_eq!(game.player().hit_points, 21);
+
+    let game = Game {
+        enemies: FpVec::from_vec(
+            game.enemies
+                .inner
+                .into_iter()
+                .enumerate()
+                .map(|(idx, enemy)| match idx {
+                    0 => Enemy {
+                        hit_points: 0,
+                        ..enemy
+                    },
+                    _ => enemy,
+                })
+                .collect(),
+        ),
+        ..game
+    };
+    let game = game.take_turn(FpVec::new());
+    assert_eq!(game.game_result, GameOutcome::Undecided);
+    // Only Floods is still standing to attack.
+    assert_eq!(game.player().hit_points, 16);
+}
+
+fn normal() -> DefenseProps {
+    DefenseProps {
+        wind: DamageAdjustment::Normal,
+        water: DamageAdjustment::Normal,
+        land: DamageAdjustment::Normal,
+        any: DamageAdjustment::Normal,
+    }
+}
+
+#[test]
+fn untargeted_cards_move_on_once_their_enemy_falls() {
+    let catalog = Catalog::builtin();
+    let weak = Enemy::new("Weak", 3, normal(), 1).player_play_card_effect(
+        OnCardPlayEffects::take_damage_on_play_elem(ElementType::Land, 5),
+    );
+    let strong = Enemy::new("Strong", 10, normal(), 1);
+    let player = Player::new(30, catalog.cards(&["gust", "stream"]).unwrap());
+    let game = Game::start_encounter(FpVec::from_vec(vec![weak, strong]), player, 1);
+    let plays = CardPlay::untargeted(game.player().hand.clone());
+    let game = game.take_player_turn(plays);
+
+    assert_eq!(hit_points(&game), vec![0, 7]);
+    // The fallen enemy no longer punishes land cards.
+    assert_eq!(game.player().hit_points, 30);
+    let order: Vec<&GameEvent> = game
+        .events
+        .inner
+        .iter()
+        .filter(|event| {
+            matches!(
+                event,
+                GameEvent::CardPlayed { .. } | GameEvent::DamageDealt { .. }
+            )
+        })
+        .collect();
+    assert!(matches!(
+        order.as_slice(),
+        [
+            GameEvent::CardPlayed { .. },
+            GameEvent::DamageDealt {
+                target: EffectTarget::EnemyAt(0),
+                ..
+            },
+            GameEvent::CardPlayed { .. },
+            GameEvent::DamageDealt {
+                target: EffectTarget::EnemyAt(1),
+                ..
+            },
+        ]
+    ));
+}
+
+#[test]
+fn cards_aimed_at_a_fallen_or_missing_enemy_are_blocked() {
+    let game = encounter(&["gust", "stream"]);
+    let game = Game {
+        enemies: FpVec::from_vec(
+            game.enemies
+                .inner
+                .into_iter()
+                .enumerate()
+                .map(|(idx, enemy)| match idx {
+                    0 => Enemy {
+                        hit_points: 0,
+                        ..enemy
+                    },
+                    _ => enemy,
+                })
+                .collect(),
+        ),
+        ..game
+    };
+    let start = hit_points(&game);
+    let plays = FpVec::from_vec(vec![
+        CardPlay::new(game.player().hand.inner[0].clone()).at(0),
+        CardPlay::new(game.player().hand.inner[1].clone()).at(2),
+    ]);
+    let game = game.take_player_turn(plays);
+
+    assert_eq!(hit_points(&game), start);
+    let blocked = game
+        .events
+        .inner
+        .iter()
+        .filter(|event| {
+            matches!(
+                event,
+                GameEvent::CardBlocked {
+                    reason: BlockReason::TargetDown,
+                    ..
+                }
+            )
+        })
+        .count();
+    assert_eq!(blocked, 2);
+    assert_eq!(game.player().hand.inner.len(), 2);
+}
@@ -0,0 +1,153 @@
+use card_game_simulator::catalog::Catalog;
+use card_game_simulator::damage::DamageStep;
+use card_game_simulator::enemy::Enemy;
+use card_game_simulator::events::{BlockReason, GameEvent};
+use card_game_simulator::fp_vec::FpVec;
+use card_game_simulator::game::{Game, GameOutcome};
+use card_game_simulator::game_effects::{CardEffects, OnCardPlayEffects};
+use card_game_simulator::player::{CardPlay, Player, PlayerCard};
+use card_game_simulator::status::Status;
+use card_game_simulator::{DamageAdjustment, DefenseProps, EffectTarget, ElementType, Enchantment};
+
+fn normal() -> DefenseProps {
+    DefenseProps {
+        wind: DamageAdjustment::Normal,
+        water: DamageAdjustment::Normal,
+        land: DamageAdjustment::Normal,
+        any: DamageAdjustment::Normal,
+    }
+}
+
+fn play_all(game: Game) -> Game {
+    let cards = CardPlay::untargeted(game.player().hand.clone());
+    game.take_player_turn(cards)
+}
+
+#[test]
+fn a_turn_reports_damage_healing_and_discards_in_order() {
+    let splash = PlayerCard::new("Splash", "", ElementType::Water)
+        .play_card_effect(CardEffects::do_element_damage(ElementType::Water, 4));
+    let mend = PlayerCard::new("Mend", "", ElementType::Land)
+        .play_card_effect(CardEffects::heal(3))
+        .play_card_effect(OnCardPlayEffects::discard_this_card());
+    let defense = DefenseProps {
+        water: DamageAdjustment::Percent(0.5),
+        ..normal()
+    };
+    let enemy = Enemy::new("Dummy", 6, defense, 2);
+    let game = play_all(Game::start(
+        enemy,
+        Player::new(10, FpVec::from_vec(vec![splash, mend])),
+    ));
+
+    assert_eq!(
+        game.events.inner,
+        vec![
+            GameEvent::CardPlayed {
+                card: "Splash".to_string()
+            },
+            GameEvent::DamageAdjusted {
+                target: EffectTarget::EnemyAt(0),
+                step: DamageStep::ElementMultiplier,
+                amount: 2,
+            },
+            GameEvent::DamageDealt {
+                target: EffectTarget::EnemyAt(0),
+                element: ElementType::Water,
+                raw: 4,
+                adjusted: 2,
+            },
+            GameEvent::CardPlayed {
+                card: "Mend".to_string()
+            },
+            GameEvent::LifeAdjusted {
+                target: EffectTarget::PlayerAt(0),
+                amount: 3,
+            },
+            GameEvent::CardDiscarded {
+                card: "Mend".to_string()
+            },
+        ]
+    );
+    assert_eq!(
+        game.take_enemy_turn().events.inner,
+        vec![GameEvent::DamageDealt {
+            target: EffectTarget::PlayerAt(0),
+            element: ElementType::NoElement,
+            raw: 2,
+            adjusted: 2,
+        }]
+    );
+}
+
+#[test]
+fn enchantments_activate_as_the_game_starts() {
+    let cards = Catalog::builtin().cards(&["env_suit"]).unwrap();
+    let game = Game::start(Enemy::new("Dummy", 6, normal(), 2), Player::new(10, cards));
+    assert_eq!(
+        game.events.inner,
+        vec![
+            GameEvent::CardDrawn {
+                card: "Environmental Suit".to_string()
+            },
+            GameEvent::EnchantmentActivated {
+                target: EffectTarget::PlayerAt(0),
+                enchantment: Enchantment::ShieldDamage(2),
+            },
+        ]
+    );
+}
+
+#[test]
+fn a_skipped_turn_is_queued_then_taken() {
+    let cards = Catalog::builtin().cards(&["time_slip"]).unwrap();
+    let game = Game::start(Enemy::new("Dummy", 6, normal(), 2), Player::new(10, cards));
+    let game = play_all(game);
+    assert!(game.events.inner.contains(&GameEvent::StatusApplied {
+        target: EffectTarget::EnemyAt(0),
+        status: Status::stun(1),
+    }));
+
+    let game = game.take_enemy_turn();
+    assert!(game.events.inner.contains(&GameEvent::TurnSkipped {
+        target: EffectTarget::EnemyAt(0),
+    }));
+    assert_eq!(game.player().hit_points, 10);
+}
+
+#[test]
+fn blocked_cards_and_the_final_blow() {
+    let haymaker = PlayerCard::new("Haymaker", "", ElementType::NoElement)
+        .cost(5)
+        .play_card_effect(CardEffects::do_physical_damage(9));
+    let jab = PlayerCard::new("Jab", "", ElementType::NoElement)
+        .play_card_effect(CardEffects::do_physical_damage(3));
+    let enemy = Enemy::new("Dummy", 3, normal(), 2);
+    let player = Player::new(10, FpVec::from_vec(vec![haymaker, jab])).power_per_turn(1);
+    let game = play_all(Game::start(enemy, player));
+
+    assert_eq!(
+        game.events.inner,
+        vec![
+            GameEvent::CardBlocked {
+                card: "Haymaker".to_string(),
+                reason: BlockReason::NotEnoughPower {
+                    cost: 5,
+                    available: 1
+                },
+            },
+            GameEvent::CardPlayed {
+                card: "Jab".to_string()
+            },
+            GameEvent::DamageDealt {
+                target: EffectTarget::EnemyAt(0),
+                element: ElementType::NoElement,
+                raw: 3,
+                adjusted: 3,
+            },
+            GameEvent::GameOver {
+                outcome: GameOutcome::PlayerWins(1)
+            },
+        ]
+    );
+}
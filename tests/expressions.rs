@@ -0,0 +1,130 @@
+use card_game_simulator::catalog::{Catalog, CatalogError};
+use card_game_simulator::condition::{Comparison, Quantity, Situation};
+use card_game_simulator::enemy::Enemy;
+use card_game_simulator::game::Game;
+use card_game_simulator::player::{CardPlay, Player, SpecialCards};
+use card_game_simulator::{CardZone, EffectCondition, ElementType};
+
+fn parse(text: &str) -> EffectCondition {
+    EffectCondition::parse(text).unwrap()
+}
+
+#[test]
+fn text_conditions_parse_into_expression_trees() {
+    assert_eq!(
+        parse("turn >= 3 and (has(water) or not enemy.hp < 50%)"),
+        EffectCondition::All(vec![
+            EffectCondition::Compare(Quantity::TurnNumber, Comparison::AtLeast, 3),
+            EffectCondition::Any(vec![
+                EffectCondition::PlayerHasCardWithElement(CardZone::Hand, ElementType::Water),
+                EffectCondition::Not(Box::new(EffectCondition::EnemyHitPointsBelowPercent(0.5))),
+            ]),
+        ])
+    );
+    // `and` binds tighter than `or`.
+    assert_eq!(
+        parse("plays(wind) or hand.land > 1 and lacks(water, discard)"),
+        EffectCondition::Any(vec![
+            EffectCondition::PlayerPlaysCardWithElement(ElementType::Wind),
+            EffectCondition::All(vec![
+                EffectCondition::Compare(
+                    Quantity::ElementCardsIn(CardZone::Hand, ElementType::Land),
+                    Comparison::Greater,
+                    1
+                ),
+                EffectCondition::PlayerHasNoCardWithElement(CardZone::Discard, ElementType::Water),
+            ]),
+        ])
+    );
+
+    for bad in [
+        "turn >=",
+        "hand.fire > 1",
+        "turn >= 3)",
+        "played < 50%",
+        "has()",
+    ] {
+        assert!(EffectCondition::parse(bad).is_err(), "{} parsed", bad);
+    }
+}
+
+#[test]
+fn expressions_check_the_situation() {
+    let player = Player::new(30, Catalog::builtin().cards(&["gust", "stream"]).unwrap());
+    let game = Game::start_seeded(Enemy::landslide(), player, 1);
+    let situation = Situation::new(game.player(), &game.enemies.inner[0], 2);
+
+    assert!(EffectCondition::All(vec![]).check(&situation));
+    assert!(!EffectCondition::Any(vec![]).check(&situation));
+    assert!(parse("hand == 2 and hand.wind == 1 and discard == 0").check(&situation));
+    assert!(EffectCondition::parse("turn == 2 and player.hp > enemy.hp").is_err());
+    assert!(parse("turn == 2 and player.hp > 20 and enemy.hp <= 20").check(&situation));
+    assert!(!parse("played > 0 or plays(wind) or enemy.skipped").check(&situation));
+
+    let gust = &game.player().hand.inner[0];
+    assert!(parse("plays(wind) and played == 1").check(&situation.playing(gust, 1)));
+}
+
+#[test]
+fn cards_check_how_many_were_played_this_turn() {
+    let catalog = Catalog::builtin();
+    assert_eq!(
+        catalog.card("riptide").unwrap().play_card_effects,
+        SpecialCards::riptide().play_card_effects
+    );
+
+    let start = |cards: &[&str]| {
+        let player = Player::new(30, catalog.cards(cards).unwrap());
+        Game::start_seeded(Enemy::landslide(), player, 1)
+    };
+    let play_all = |game: Game| {
+        let cards = CardPlay::untargeted(game.player().hand.clone());
+        game.take_player_turn(cards)
+    };
+    // Landslide takes 1 less from every hit.
+    let game = play_all(start(&["riptide"]));
+    assert_eq!(game.enemies.inner[0].hit_points, 19);
+    // Only the third riptide resolves with three cards played.
+    let game = play_all(start(&["riptide", "riptide", "riptide"]));
+    assert_eq!(game.player().cards_played, 3);
+    assert_eq!(game.enemies.inner[0].hit_points, 15);
+
+    let game = game.take_enemy_turn();
+    assert_eq!(game.player().cards_played, 0);
+}
+
+#[test]
+fn catalog_effects_take_text_conditions() {
+    let effect = |condition: &str| {
+        format!(
+            r#"
+            [[card]]
+            key = "test"
+            name = "Test"
+            description = ""
+            element = "none"
+            play_card_effects = [
+                {{ name = "Heal", target = "player", {}, effect = {{ type = "life_adjust", amount = 1 }} }},
+            ]
+            "#,
+            condition
+        )
+    };
+    let catalog = Catalog::from_toml_str(&effect(r#"when = "turn > 1""#)).unwrap();
+    assert!(catalog.card("test").unwrap().play_card_effects.inner[0]
+        .description()
+        .contains("TURN > 1"));
+
+    let nested = r#"condition = { type = "not", condition = { type = "any", of = [{ type = "enemy_skipped_last_turn" }] } }"#;
+    assert!(Catalog::from_toml_str(&effect(nested)).is_ok());
+
+    for bad in [
+        r#"when = "turn >""#,
+        r#"when = "turn > 1", condition = { type = "enemy_skipped_last_turn" }"#,
+    ] {
+        assert!(matches!(
+            Catalog::from_toml_str(&effect(bad)),
+            Err(CatalogError::InvalidEntry { .. })
+        ));
+    }
+}
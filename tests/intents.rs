@@ -0,0 +1,97 @@
+use card_game_simulator::catalog::Catalog;
+use card_game_simulator::enemy::Enemy;
+use card_game_simulator::events::GameEvent;
+use card_game_simulator::fp_vec::FpVec;
+use card_game_simulator::game::Game;
+use card_game_simulator::player::Player;
+use card_game_simulator::{EffectTarget, ElementType, Enchantment};
+
+fn solo(enemy: Enemy, seed: u64) -> Game {
+    let catalog = Catalog::builtin();
+    Game::start_seeded(
+        enemy,
+        Player::new(30, catalog.cards(&["stream"]).unwrap()),
+        seed,
+    )
+}
+
+fn intent(game: &Game, idx: usize) -> Option<String> {
+    game.enemies.inner[idx]
+        .intended_move()
+        .map(|intent| intent.name.clone())
+}
+
+#[test]
+fn catalog_enemies_match_their_constructors() {
+    let catalog = Catalog::builtin();
+    assert_eq!(catalog.enemy("wildfire").unwrap(), Enemy::wildfire());
+    assert_eq!(catalog.enemy("hurricane").unwrap(), Enemy::hurricane());
+}
+
+#[test]
+fn scripted_moves_cycle_and_show_the_next_one() {
+    let game = solo(Enemy::wildfire(), 1);
+    assert_eq!(intent(&game, 0).as_deref(), Some("Smolder"));
+    assert!(game.enemies.inner[0]
+        .description()
+        .contains("Intent: Smolder"));
+
+    let game = game.take_turn(FpVec::new());
+    assert_eq!(game.player().hit_points, 30);
+    assert_eq!(intent(&game, 0).as_deref(), Some("Firestorm"));
+
+    let game = game.take_turn(FpVec::new());
+    assert_eq!(game.player().hit_points, 21);
+    assert_eq!(intent(&game, 0).as_deref(), Some("Spread"));
+
+    let game = game.take_turn(FpVec::new());
+    assert_eq!(game.enemies.inner.len(), 2);
+    assert_eq!(game.enemies.inner[1].name, "Ember");
+    assert!(game.events.inner.contains(&GameEvent::EnemySummoned {
+        target: EffectTarget::EnemyAt(1),
+        name: "Ember".to_string(),
+    }));
+    assert_eq!(intent(&game, 0).as_deref(), Some("Smolder"));
+
+    // The ember joins in from the next round.
+    let game = game.take_turn(FpVec::new());
+    assert_eq!(game.player().hit_points, 20);
+}
+
+#[test]
+fn weighted_moves_follow_the_seed() {
+    let intents = |seed| {
+        (0..10)
+            .scan(solo(Enemy::hurricane(), seed), |game, _| {
+                let name = intent(game, 0);
+                *game = game.clone().take_turn(FpVec::new());
+                Some(name)
+            })
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(intents(7), intents(7));
+
+    let seen: Vec<Option<String>> = (0..20).flat_map(intents).collect();
+    [
+        "Gale",
+        "Eye of the Storm",
+        "Tangling Winds",
+        "Spawn Storm Cell",
+    ]
+    .iter()
+    .for_each(|name| assert!(seen.contains(&Some(name.to_string())), "{}", name));
+}
+
+#[test]
+fn moves_can_enchant_the_player() {
+    let game = (0..20)
+        .map(|seed| solo(Enemy::hurricane(), seed))
+        .find(|game| intent(game, 0).as_deref() == Some("Tangling Winds"))
+        .unwrap();
+    let game = game.take_turn(FpVec::new());
+    assert!(game
+        .player()
+        .current_activated_effects
+        .inner
+        .contains(&Enchantment::SpellDamageAdjust(ElementType::Wind, -1)));
+}
@@ -0,0 +1,87 @@
+use card_game_simulator::enemy::Enemy;
+use card_game_simulator::events::GameEvent;
+use card_game_simulator::fp_vec::FpVec;
+use card_game_simulator::game::{Game, GameOutcome, TieRule};
+use card_game_simulator::game_effects::{CardEffects, OnCardPlayEffects};
+use card_game_simulator::phase::Phase;
+use card_game_simulator::player::{CardPlay, Player, PlayerCard};
+use card_game_simulator::replay::Replay;
+use card_game_simulator::{DefenseProps, ElementType};
+
+fn backlash(amount: i32) -> Enemy {
+    Enemy::new("Dummy", 3, DefenseProps::normal(), 2).player_play_card_effect(
+        OnCardPlayEffects::take_damage_on_play_elem(ElementType::Water, amount),
+    )
+}
+
+fn splash() -> PlayerCard {
+    PlayerCard::new("Splash", "", ElementType::Water)
+        .play_card_effect(CardEffects::do_element_damage(ElementType::Water, 3))
+        .play_card_effect(CardEffects::heal(10))
+}
+
+fn play_splash(game: Game) -> Game {
+    let cards = CardPlay::untargeted(game.player().hand.clone());
+    game.take_turn(cards)
+}
+
+#[test]
+fn backlash_from_the_finishing_card_ties_the_game() {
+    let start = || {
+        Game::start_seeded(
+            backlash(5),
+            Player::new(5, FpVec::from_vec(vec![splash()])),
+            1,
+        )
+    };
+
+    let game = play_splash(start());
+    assert_eq!(game.game_result, GameOutcome::Draw(1));
+    assert!(game.events.inner.contains(&GameEvent::GameOver {
+        outcome: GameOutcome::Draw(1)
+    }));
+
+    let game = play_splash(start().tie_rule(TieRule::AttackerWins));
+    assert_eq!(game.game_result, GameOutcome::PlayerWins(1));
+}
+
+#[test]
+fn counters_land_after_the_card_felled_the_enemy() {
+    let player = Player::new(10, FpVec::from_vec(vec![splash()]));
+    let game = play_splash(Game::start_seeded(backlash(2), player, 1));
+    assert_eq!(game.game_result, GameOutcome::PlayerWins(1));
+    // The heal after the finishing blow never resolved, but the backlash did.
+    assert_eq!(game.player().hit_points, 8);
+}
+
+#[test]
+fn ties_go_to_the_side_whose_turn_it_is() {
+    let attacker = TieRule::AttackerWins;
+    assert_eq!(
+        attacker.outcome(Phase::PlayerMain, 3),
+        GameOutcome::PlayerWins(3)
+    );
+    assert_eq!(
+        attacker.outcome(Phase::EnemyAction, 3),
+        GameOutcome::EnemyWins(3)
+    );
+    assert_eq!(
+        TieRule::default().outcome(Phase::EnemyAction, 3),
+        GameOutcome::Draw(3)
+    );
+
+    let player = Player::new(5, FpVec::from_vec(vec![splash()]));
+    let replay = Replay::new(backlash(5), player, 1).tie_rule(TieRule::AttackerWins);
+    assert_eq!(replay.start().tie_rule, TieRule::AttackerWins);
+}
+
+#[test]
+fn counters_cannot_heal_a_fallen_enemy_back() {
+    let enemy = Enemy::new("Dummy", 3, DefenseProps::normal(), 2).player_play_card_effect(
+        OnCardPlayEffects::heal_enemy_on_play_elem(ElementType::Water, 5),
+    );
+    let player = Player::new(10, FpVec::from_vec(vec![splash()]));
+    let game = play_splash(Game::start_seeded(enemy, player, 1));
+    assert_eq!(game.game_result, GameOutcome::PlayerWins(1));
+    assert_eq!(game.enemies.inner[0].hit_points, 0);
+}
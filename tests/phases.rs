@@ -0,0 +1,97 @@
+use card_game_simulator::catalog::Catalog;
+use card_game_simulator::enemy::Enemy;
+use card_game_simulator::fp_vec::FpVec;
+use card_game_simulator::game::{Game, GameOutcome};
+use card_game_simulator::game_effects::{CardEffects, EnemyEffects, GameEffect};
+use card_game_simulator::phase::Phase;
+use card_game_simulator::player::{CardPlay, Player, PlayerCard, SpecialCards};
+use card_game_simulator::{DefenseProps, EffectTrigger, EffectType, ElementType};
+
+fn dummy() -> Enemy {
+    Enemy::new("Dummy", 20, DefenseProps::normal(), 2)
+}
+
+fn solo(cards: Vec<PlayerCard>, enemy: Enemy) -> Game {
+    Game::start_seeded(enemy, Player::new(30, FpVec::from_vec(cards)), 1)
+}
+
+#[test]
+fn turns_move_through_the_phases() {
+    let players = FpVec::from_vec(vec![
+        Player::new(30, FpVec::new()),
+        Player::new(30, FpVec::new()),
+    ]);
+    let game = Game::start_coop(FpVec::from_vec(vec![dummy()]), players, 1);
+    assert_eq!(game.phase, Phase::PlayerMain);
+
+    let game = game.take_player_turn(FpVec::new());
+    assert_eq!((game.phase, game.current_player), (Phase::PlayerMain, 1));
+    let game = game.take_player_turn(FpVec::new());
+    assert_eq!((game.phase, game.current_player), (Phase::EnemyUpkeep, 1));
+
+    // Every player has had their turn, so only the enemies can go.
+    let again = game.clone().take_player_turn(FpVec::new());
+    assert!(again.events.inner.is_empty());
+    assert_eq!(again.players, game.players);
+
+    let game = game.take_enemy_turn();
+    assert_eq!(game.phase, Phase::PlayerMain);
+    assert_eq!(game.turn_number, 2);
+    assert!(game.take_enemy_turn().events.inner.is_empty());
+}
+
+#[test]
+fn legal_plays_follow_the_phase_and_the_power_left() {
+    let cheap = PlayerCard::new("Cheap", "", ElementType::Wind);
+    let dear = PlayerCard::new("Dear", "", ElementType::Land).cost(2);
+    let game = solo(vec![cheap, dear], dummy());
+    let names = |game: &Game| {
+        game.legal_plays()
+            .inner
+            .into_iter()
+            .map(|card| card.name)
+            .collect::<Vec<String>>()
+    };
+    assert_eq!(names(&game), vec!["Cheap"]);
+    assert_eq!(game.legal_targets(), vec![0]);
+
+    let game = game.take_player_turn(FpVec::new());
+    assert!(names(&game).is_empty());
+    assert!(game.legal_targets().is_empty());
+}
+
+#[test]
+fn hooks_resolve_as_their_phase_begins() {
+    assert_eq!(
+        Catalog::builtin().card("sea_wall").unwrap().phase_effects,
+        SpecialCards::sea_wall().phase_effects
+    );
+    let enemy = dummy().phase_effect(Phase::Cleanup, EnemyEffects::heal_self(3));
+    assert!(enemy
+        .description()
+        .contains("Phase Effects [cleanup: Enemy Heals"));
+
+    let game = solo(vec![SpecialCards::sea_wall()], enemy);
+    let game = game.take_player_turn(FpVec::new());
+    assert_eq!(game.enemies.inner[0].hit_points, 20);
+    let game = game.take_enemy_turn();
+    // The sea wall hit for 1 as the enemy's turn ended, then it healed.
+    assert_eq!(game.enemies.inner[0].hit_points, 22);
+    assert_eq!(game.player().hit_points, 28);
+}
+
+#[test]
+fn nothing_resolves_once_a_side_falls() {
+    let regrowth = GameEffect::enemy(
+        "Regrowth",
+        EffectTrigger::Always(EffectType::LifeAdjust(40)),
+    );
+    let finisher = PlayerCard::new("Finisher", "", ElementType::NoElement)
+        .play_card_effect(CardEffects::do_physical_damage(25))
+        .play_card_effect(regrowth);
+    let game = solo(vec![finisher], dummy());
+    let cards = CardPlay::untargeted(game.player().hand.clone());
+    let game = game.take_turn(cards);
+    assert_eq!(game.game_result, GameOutcome::PlayerWins(1));
+    assert_eq!(game.enemies.inner[0].hit_points, -5);
+}
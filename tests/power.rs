@@ -0,0 +1,96 @@
+use card_game_simulator::enemy::Enemy;
+use card_game_simulator::events::{BlockReason, GameEvent};
+use card_game_simulator::fp_vec::FpVec;
+use card_game_simulator::game::Game;
+use card_game_simulator::game_effects::{CardEffects, Enchantments};
+use card_game_simulator::player::{CardPlay, Player, PlayerCard};
+use card_game_simulator::{
+    DamageAdjustment, DefenseProps, ElementType, Enchantment, PowerCostAdjust,
+};
+
+fn card(name: &str, element: ElementType, cost: i32) -> PlayerCard {
+    PlayerCard::new(name, "", element)
+        .cost(cost)
+        .play_card_effect(CardEffects::do_physical_damage(2))
+}
+
+fn start(cards: Vec<PlayerCard>) -> Game {
+    let player = Player::new(30, FpVec::from_vec(cards)).power_per_turn(3);
+    let defense = DefenseProps {
+        wind: DamageAdjustment::Normal,
+        water: DamageAdjustment::Normal,
+        land: DamageAdjustment::Normal,
+        any: DamageAdjustment::Normal,
+    };
+    Game::start(Enemy::new("Dummy", 20, defense, 1), player)
+}
+
+fn play_all(game: Game) -> Game {
+    let cards = CardPlay::untargeted(game.player().hand.clone());
+    game.take_player_turn(cards)
+}
+
+#[test]
+fn cards_are_blocked_once_the_power_runs_out() {
+    let game = start(vec![
+        card("Gust", ElementType::Wind, 2),
+        card("Stream", ElementType::Land, 2),
+    ]);
+    assert_eq!(game.player().power, 3);
+    let game = play_all(game);
+
+    assert_eq!(game.enemies.inner[0].hit_points, 18);
+    assert_eq!(game.player().power, 1);
+    assert!(game.events.inner.contains(&GameEvent::CardBlocked {
+        card: "Stream".to_string(),
+        reason: BlockReason::NotEnoughPower {
+            cost: 2,
+            available: 1
+        },
+    }));
+}
+
+#[test]
+fn power_refills_as_each_turn_starts() {
+    let game = play_all(start(vec![card("Gust", ElementType::Wind, 3)]));
+    assert_eq!(game.player().power, 0);
+
+    let game = game.take_enemy_turn();
+    assert_eq!(game.player().power, 3);
+}
+
+#[test]
+fn cost_adjustments_apply_to_their_element_down_to_zero() {
+    let adjust = |element: ElementType, amount: i32| {
+        Enchantment::PowerCostAdjust(PowerCostAdjust {
+            card_type: element,
+            amount,
+        })
+    };
+    let player = Player {
+        current_activated_effects: FpVec::from_vec(vec![
+            adjust(ElementType::Wind, 2),
+            adjust(ElementType::Land, -5),
+        ]),
+        ..Player::new(30, FpVec::new())
+    };
+    assert_eq!(player.card_cost(&card("Gust", ElementType::Wind, 1)), 3);
+    assert_eq!(player.card_cost(&card("Stream", ElementType::Land, 2)), 0);
+    assert_eq!(player.card_cost(&card("Tide", ElementType::Water, 2)), 2);
+}
+
+#[test]
+fn cheaper_cards_fit_in_the_power_left() {
+    let discount = PlayerCard::new("Discount", "", ElementType::Land).game_start_effect(
+        Enchantments::player_elem_spell_cost_adj(ElementType::Land, -1),
+    );
+    let game = start(vec![
+        discount,
+        card("Stream", ElementType::Land, 2),
+        card("Quake", ElementType::Land, 2),
+    ]);
+    let game = play_all(game);
+    // Discount is free, and the two land cards cost 1 each.
+    assert_eq!(game.enemies.inner[0].hit_points, 16);
+    assert_eq!(game.player().power, 1);
+}
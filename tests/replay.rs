@@ -0,0 +1,57 @@
+use card_game_simulator::catalog::Catalog;
+use card_game_simulator::fp_vec::FpVec;
+use card_game_simulator::game_effects::EnemyEffects;
+use card_game_simulator::player::{CardPlay, Player};
+use card_game_simulator::replay::{Divergence, Replay};
+use card_game_simulator::save::{load_replay, save_replay};
+
+fn recorded(turns: u32) -> Replay {
+    let catalog = Catalog::builtin();
+    let player = Player::new(
+        20,
+        catalog
+            .cards(&["gust", "stream", "time_slip", "first_aid"])
+            .unwrap(),
+    );
+    let replay = Replay::new(catalog.enemy("drought").unwrap(), player, 42);
+    let (replay, _) = (0..turns).fold((replay.clone(), replay.start()), |(replay, game), _| {
+        let cards = FpVec::from_vec(game.player().hand.inner.iter().take(2).cloned().collect());
+        let cards = CardPlay::untargeted(cards);
+        let game = game.take_turn(cards.clone());
+        (replay.record(&cards, &game), game)
+    });
+    replay
+}
+
+#[test]
+fn recorded_game_plays_back_without_divergence() {
+    let replay = recorded(4);
+    let playback = replay.play_back();
+    assert_eq!(playback.divergence, None);
+    assert_eq!(playback.games.len(), 5);
+}
+
+#[test]
+fn replay_survives_a_round_trip_through_a_file() {
+    let replay = recorded(3);
+    let path = std::env::temp_dir().join("card_game_simulator_replay_test.json");
+    save_replay(&replay, &path).unwrap();
+    let loaded = load_replay(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(loaded, replay);
+}
+
+#[test]
+fn changed_rules_are_reported_as_divergence() {
+    let replay = recorded(3);
+    let changed = Replay {
+        enemies: FpVec::from_vec(vec![replay.enemies.inner[0]
+            .clone()
+            .end_turn_effect(EnemyEffects::attack(1))]),
+        ..replay
+    };
+    match changed.play_back().divergence {
+        Some(Divergence::TurnDiffers { turn, .. }) => assert_eq!(turn, 1),
+        other => panic!("expected the first turn to differ, got {:?}", other),
+    }
+}
@@ -0,0 +1,44 @@
+use card_game_simulator::catalog::Catalog;
+use card_game_simulator::enemy::Enemy;
+use card_game_simulator::fp_vec::FpVec;
+use card_game_simulator::game::Game;
+use card_game_simulator::player::Player;
+use card_game_simulator::{DamageAdjustment, DefenseProps};
+
+fn after_one_round(player: Player, enemy: Enemy) -> i32 {
+    let game = Game::start_seeded(enemy, player, 1).take_turn(FpVec::new());
+    game.player().hit_points
+}
+
+#[test]
+fn enemies_attack_with_elements() {
+    let catalog = Catalog::builtin();
+    assert_eq!(catalog.enemy("flash_flood").unwrap(), Enemy::flash_flood());
+
+    let player = Player::new(30, catalog.cards(&["stream"]).unwrap());
+    assert_eq!(after_one_round(player, Enemy::flash_flood()), 26);
+}
+
+#[test]
+fn players_resist_elements_by_their_defense() {
+    let cards = Catalog::builtin().cards(&["stream"]).unwrap();
+    let resistant = Player::new(30, cards.clone()).defense_props(DefenseProps {
+        water: DamageAdjustment::Percent(0.5),
+        ..DefenseProps::normal()
+    });
+    assert_eq!(after_one_round(resistant, Enemy::flash_flood()), 28);
+
+    let immune = Player::new(30, cards).defense_props(DefenseProps {
+        water: DamageAdjustment::Percent(0.0),
+        ..DefenseProps::normal()
+    });
+    assert_eq!(after_one_round(immune, Enemy::flash_flood()), 30);
+}
+
+#[test]
+fn cards_grant_elemental_resistance() {
+    let catalog = Catalog::builtin();
+    let player = Player::new(30, catalog.cards(&["dry_suit"]).unwrap());
+    assert_eq!(after_one_round(player.clone(), Enemy::flash_flood()), 28);
+    assert_eq!(after_one_round(player, Enemy::landslide()), 28);
+}
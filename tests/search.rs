@@ -0,0 +1,95 @@
+use card_game_simulator::catalog::Catalog;
+use card_game_simulator::enemy::Enemy;
+use card_game_simulator::fp_vec::FpVec;
+use card_game_simulator::game::Game;
+use card_game_simulator::game_effects::CardEffects;
+use card_game_simulator::player::{CardPlay, Player, PlayerCard};
+use card_game_simulator::rng::GameRng;
+use card_game_simulator::search::{best_play, candidate_plays, SearchConfig};
+use card_game_simulator::{DamageAdjustment, DefenseProps, ElementType};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+fn dummy() -> Enemy {
+    let defense = DefenseProps {
+        wind: DamageAdjustment::Normal,
+        water: DamageAdjustment::Normal,
+        land: DamageAdjustment::Normal,
+        any: DamageAdjustment::Normal,
+    };
+    Enemy::new("Dummy", 20, defense, 1)
+}
+
+fn names(plays: &FpVec<CardPlay>) -> Vec<String> {
+    plays
+        .inner
+        .iter()
+        .map(|play| play.card.name.clone())
+        .collect()
+}
+
+#[test]
+fn the_search_cannot_see_the_games_dice() {
+    let catalog = Catalog::builtin();
+    let cards = catalog
+        .cards(&["gust", "stream", "fire_hose", "first_aid", "jet_blast"])
+        .unwrap();
+    let player = Player::new(20, cards).starting_hand(2).draws_per_turn(1);
+    let game = Game::start_seeded(catalog.enemy("volcano").unwrap(), player, 7);
+    let reseeded = Game {
+        players: FpVec::from_vec(vec![Player {
+            library: FpVec::from_vec(game.player().library.inner.iter().rev().cloned().collect()),
+            ..game.player().clone()
+        }]),
+        rng: GameRng::new(99),
+        ..game.clone()
+    };
+    let config = SearchConfig::default();
+    let found = best_play(&game, &config, &mut StdRng::seed_from_u64(1));
+    let again = best_play(&reseeded, &config, &mut StdRng::seed_from_u64(1));
+    assert_eq!(found, again);
+}
+
+#[test]
+fn copies_of_a_card_make_one_candidate_each() {
+    let cheap = || PlayerCard::new("Cheap", "", ElementType::Wind).cost(1);
+    let dear = PlayerCard::new("Dear", "", ElementType::Land).cost(2);
+    let player = Player::new(20, FpVec::from_vec(vec![cheap(), cheap(), dear])).power_per_turn(2);
+    let game = Game::start_seeded(dummy(), player, 1);
+
+    let plays: Vec<Vec<String>> = candidate_plays(&game).map(|cards| names(&cards)).collect();
+    assert_eq!(
+        plays,
+        vec![
+            vec![],
+            vec!["Cheap".to_string()],
+            vec!["Cheap".to_string(), "Cheap".to_string()],
+            vec!["Dear".to_string()],
+        ]
+    );
+    let both = candidate_plays(&game).nth(2).unwrap();
+    assert_ne!(both.inner[0].card.id, both.inner[1].card.id);
+}
+
+#[test]
+fn the_best_play_so_far_is_kept_when_no_pass_finishes() {
+    let jab = PlayerCard::new("Jab", "", ElementType::NoElement)
+        .cost(1)
+        .play_card_effect(CardEffects::do_physical_damage(3));
+    let wait = PlayerCard::new("Wait", "", ElementType::NoElement).cost(1);
+    let player = Player::new(20, FpVec::from_vec(vec![jab, wait])).power_per_turn(2);
+    let game = Game::start_seeded(dummy(), player, 1);
+    // Enough to score doing nothing and the Jab, but not the rest.
+    let config = SearchConfig {
+        depth: 1,
+        node_budget: 2,
+        samples: 1,
+        ..SearchConfig::default()
+    };
+
+    let found = best_play(&game, &config, &mut StdRng::seed_from_u64(1));
+    assert_eq!(
+        (names(&found.cards), found.depth),
+        (vec!["Jab".to_string()], 0)
+    );
+}
@@ -0,0 +1,54 @@
+use card_game_simulator::catalog::Catalog;
+use card_game_simulator::game::Game;
+use card_game_simulator::game_effects::Enchantments;
+use card_game_simulator::player::{CardPlay, Player};
+use card_game_simulator::versioned::{VersionError, VersionHeader, Versioned, FORMAT_VERSION};
+use card_game_simulator::ElementType;
+
+fn game_in_progress() -> Game {
+    let catalog = Catalog::builtin();
+    let player = Player::new(
+        20,
+        catalog
+            .cards(&["gust", "fire_hose", "time_slip", "hydro_power", "env_suit"])
+            .unwrap(),
+    );
+    let enemy = catalog.enemy("meltdown").unwrap().enchantment(
+        Enchantments::player_take_damage_elem_card_present(ElementType::Water, 1),
+    );
+    let game = Game::start(enemy, player);
+    let cards = CardPlay::untargeted(game.player().hand.clone());
+    game.take_player_turn(cards).take_enemy_turn()
+}
+
+#[test]
+fn game_round_trips_through_json() {
+    let game = game_in_progress();
+    let text = serde_json::to_string(&Versioned::new(game.clone())).unwrap();
+    let loaded: Versioned<Game> = serde_json::from_str(&text).unwrap();
+    assert_eq!(loaded.into_current().unwrap(), game);
+}
+
+#[test]
+fn game_round_trips_through_toml() {
+    let game = game_in_progress();
+    let text = toml::to_string(&Versioned::new(game.clone())).unwrap();
+    let loaded: Versioned<Game> = toml::from_str(&text).unwrap();
+    assert_eq!(loaded.into_current().unwrap(), game);
+}
+
+#[test]
+fn unsupported_version_is_reported() {
+    let text = format!(
+        "{{\"format_version\": {}, \"data\": {{}}}}",
+        FORMAT_VERSION + 1
+    );
+    let header: VersionHeader = serde_json::from_str(&text).unwrap();
+    assert_eq!(
+        header.check(),
+        Err(VersionError::Unsupported {
+            found: FORMAT_VERSION + 1,
+            expected: FORMAT_VERSION
+        })
+    );
+}
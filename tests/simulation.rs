@@ -0,0 +1,73 @@
+use card_game_simulator::catalog::Catalog;
+use card_game_simulator::enemy::Enemy;
+use card_game_simulator::fp_vec::FpVec;
+use card_game_simulator::game::Game;
+use card_game_simulator::game_effects::CardEffects;
+use card_game_simulator::player::{Player, PlayerCard};
+use card_game_simulator::simulation::{simulate, SimulationConfig};
+use card_game_simulator::strategy::{PlayAllStrategy, PlayerStrategy, RandomStrategy};
+use card_game_simulator::{DamageAdjustment, DefenseProps, ElementType};
+use rand::seq::SliceRandom;
+use std::cell::Cell;
+
+fn config(games: u32) -> SimulationConfig {
+    SimulationConfig {
+        games,
+        ..SimulationConfig::default()
+    }
+}
+
+#[test]
+fn the_same_seed_gives_the_same_report() {
+    let catalog = Catalog::builtin();
+    let deck = catalog
+        .cards(&["gust", "stream", "first_aid", "fire_hose"])
+        .unwrap();
+    let run = || {
+        simulate(
+            &config(50),
+            |rng| {
+                let mut cards = deck.inner.clone();
+                cards.shuffle(rng);
+                let player = Player::new(20, FpVec::from_vec(cards));
+                Game::start(catalog.enemy("landslide").unwrap(), player)
+            },
+            |seed| Box::new(RandomStrategy::new(seed)) as Box<dyn PlayerStrategy>,
+        )
+    };
+    let report = run();
+    assert_eq!(report, run());
+    assert_eq!(report.description(), run().description());
+}
+
+#[test]
+fn statistics_of_a_known_setup() {
+    // Jab hits 3 a turn and the enemy 2: a 3 hp enemy falls on turn 1, a 9 on turn 3.
+    let jab = PlayerCard::new("Jab", "", ElementType::NoElement)
+        .play_card_effect(CardEffects::do_physical_damage(3));
+    let defense = DefenseProps {
+        wind: DamageAdjustment::Normal,
+        water: DamageAdjustment::Normal,
+        land: DamageAdjustment::Normal,
+        any: DamageAdjustment::Normal,
+    };
+    let hit_points = [3, 9, 9, 30];
+    let game_idx = Cell::new(0);
+    let report = simulate(
+        &config(4),
+        |_| {
+            let idx = game_idx.replace(game_idx.get() + 1);
+            let enemy = Enemy::new("Dummy", hit_points[idx], defense.clone(), 2);
+            let player = Player::new(10, FpVec::from_vec(vec![jab.clone()])).draws_per_turn(1);
+            Game::start(enemy, player)
+        },
+        |_| Box::new(PlayAllStrategy) as Box<dyn PlayerStrategy>,
+    );
+
+    assert_eq!((report.wins(), report.losses()), (3, 1));
+    assert_eq!(report.win_rate(), 0.75);
+    let turns = report.turns_to_win().unwrap();
+    assert_eq!((turns.min, turns.median, turns.max), (1, 3.0, 3));
+    assert_eq!(report.enemy_hit_points_on_loss().unwrap().median, 15.0);
+    assert_eq!(report.card_plays().get("Jab"), Some(&12));
+}
@@ -0,0 +1,122 @@
+use card_game_simulator::catalog::Catalog;
+use card_game_simulator::enemy::Enemy;
+use card_game_simulator::events::GameEvent;
+use card_game_simulator::fp_vec::FpVec;
+use card_game_simulator::game::Game;
+use card_game_simulator::game_effects::{CardEffects, EnemyEffects, GameEffect};
+use card_game_simulator::player::{CardPlay, Player, PlayerCard};
+use card_game_simulator::status::{Status, StatusKind};
+use card_game_simulator::{EffectTarget, ElementType};
+
+fn card(effect: GameEffect) -> PlayerCard {
+    PlayerCard::new("Test", "", ElementType::NoElement).play_card_effect(effect)
+}
+
+fn solo(cards: Vec<PlayerCard>, enemy: Enemy) -> Game {
+    Game::start_seeded(enemy, Player::new(30, FpVec::from_vec(cards)), 1)
+}
+
+fn play_all(game: Game) -> Game {
+    let cards = CardPlay::untargeted(game.player().hand.clone());
+    game.take_turn(cards)
+}
+
+#[test]
+fn burn_ticks_each_turn_until_it_wears_off() {
+    let game = solo(
+        vec![card(CardEffects::afflict_enemy(Status::burn(2, 2)))],
+        Enemy::landslide(),
+    );
+    // Landslide shrugs off a point of every hit, burns included.
+    let game = play_all(game);
+    assert_eq!(game.enemies.inner[0].hit_points, 19);
+    assert_eq!(game.enemies.inner[0].statuses.inner[0].turns, 1);
+
+    let game = game.take_turn(FpVec::new());
+    assert_eq!(game.enemies.inner[0].hit_points, 18);
+    assert!(game.enemies.inner[0].statuses.inner.is_empty());
+    assert!(game.events.inner.iter().any(|event| matches!(
+        event,
+        GameEvent::StatusExpired { target: EffectTarget::EnemyAt(0), status } if status.kind == StatusKind::Burn
+    )));
+}
+
+#[test]
+fn poison_loses_a_stack_each_tick() {
+    let enemy = Enemy::landslide()
+        .end_turn_effects_cleared()
+        .end_turn_effect(EnemyEffects::afflict_player(Status::poison(3)));
+    let game = solo(vec![], enemy.clone());
+    // Poisoned on the enemy's first turn, then ticking 3, 2 and 1 as it is topped up.
+    let game = game.take_turn(FpVec::new());
+    assert_eq!(game.player().statuses.inner[0].stacks, 3);
+    let game = game.take_turn(FpVec::new());
+    assert_eq!(game.player().hit_points, 27);
+    assert_eq!(game.player().statuses.inner[0].stacks, 5);
+}
+
+#[test]
+fn stunned_enemies_and_players_lose_their_turn() {
+    let game = solo(
+        vec![card(CardEffects::skip_enemy_turn())],
+        Enemy::landslide(),
+    );
+    let game = play_all(game);
+    assert_eq!(game.player().hit_points, 30);
+    assert!(game.events.inner.contains(&GameEvent::TurnSkipped {
+        target: EffectTarget::EnemyAt(0)
+    }));
+    assert!(game.enemies.inner[0].statuses.inner.is_empty());
+
+    let enemy = Enemy::landslide().end_turn_effect(EnemyEffects::afflict_player(Status::stun(1)));
+    let game = solo(vec![card(CardEffects::do_physical_damage(5))], enemy);
+    let game = game.take_turn(FpVec::new());
+    let enemy_hp = game.enemies.inner[0].hit_points;
+    let game = play_all(game);
+    assert_eq!(game.enemies.inner[0].hit_points, enemy_hp);
+    assert!(game.events.inner.contains(&GameEvent::TurnSkipped {
+        target: EffectTarget::PlayerAt(0)
+    }));
+}
+
+#[test]
+fn regeneration_and_vulnerability_apply_to_either_side() {
+    let game = solo(
+        vec![
+            Catalog::builtin().card("fire_retardant").unwrap(),
+            card(CardEffects::do_element_damage(ElementType::Water, 3)),
+            card(CardEffects::bless_ally(Status::regeneration(2, 3))),
+        ],
+        Enemy::landslide(),
+    );
+    let game = play_all(game);
+    // Landslide takes 1 less from everything; the retardant adds 2 to water.
+    assert_eq!(game.enemies.inner[0].hit_points, 16);
+    // Regenerated 2 at the end of the turn, then hit for 2.
+    assert_eq!(game.player().hit_points, 30);
+
+    let enemy = Enemy::landslide().end_turn_effect(EnemyEffects::afflict_player(
+        Status::vulnerable(ElementType::NoElement, 1, 2),
+    ));
+    let game = solo(vec![], enemy).take_turn(FpVec::new());
+    assert_eq!(game.player().hit_points, 28);
+    let game = game.take_turn(FpVec::new());
+    assert_eq!(game.player().hit_points, 25);
+}
+
+#[test]
+fn statuses_of_one_kind_stack() {
+    let game = solo(
+        vec![
+            card(CardEffects::afflict_enemy(Status::burn(1, 2))),
+            card(CardEffects::afflict_enemy(Status::burn(2, 3))),
+        ],
+        Enemy::landslide(),
+    );
+    let cards = CardPlay::untargeted(game.player().hand.clone());
+    let game = game.take_player_turn(cards);
+    assert_eq!(
+        game.enemies.inner[0].statuses,
+        FpVec::from_vec(vec![Status::burn(3, 3)])
+    );
+}
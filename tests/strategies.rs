@@ -0,0 +1,124 @@
+use card_game_simulator::enemy::Enemy;
+use card_game_simulator::fp_vec::FpVec;
+use card_game_simulator::game::Game;
+use card_game_simulator::game_effects::{CardEffects, OnCardPlayEffects};
+use card_game_simulator::player::{CardPlay, Player, PlayerCard};
+use card_game_simulator::strategy::{
+    ConservativeHealStrategy, GreedyDamageStrategy, PlayerStrategy,
+};
+use card_game_simulator::{DamageAdjustment, DefenseProps, ElementType};
+
+fn splash() -> PlayerCard {
+    PlayerCard::new("Splash", "", ElementType::Water)
+        .cost(1)
+        .play_card_effect(CardEffects::do_element_damage(ElementType::Water, 4))
+}
+
+fn gust() -> PlayerCard {
+    PlayerCard::new("Gust", "", ElementType::Wind)
+        .cost(1)
+        .play_card_effect(CardEffects::do_element_damage(ElementType::Wind, 3))
+}
+
+fn mend() -> PlayerCard {
+    PlayerCard::new("Mend", "", ElementType::Land)
+        .cost(1)
+        .play_card_effect(CardEffects::heal(3))
+}
+
+fn defense(wind: DamageAdjustment, water: DamageAdjustment) -> DefenseProps {
+    DefenseProps {
+        wind,
+        water,
+        land: DamageAdjustment::Normal,
+        any: DamageAdjustment::Normal,
+    }
+}
+
+fn dummy() -> Enemy {
+    let defense = defense(DamageAdjustment::Normal, DamageAdjustment::Normal);
+    Enemy::new("Dummy", 20, defense, 2)
+}
+
+fn dam() -> Enemy {
+    let defense = defense(DamageAdjustment::Normal, DamageAdjustment::Percent(0.0));
+    Enemy::new("Dam", 20, defense, 2)
+}
+
+fn windbreak() -> Enemy {
+    let defense = defense(DamageAdjustment::Percent(0.0), DamageAdjustment::Normal);
+    Enemy::new("Windbreak", 20, defense, 2)
+}
+
+fn start(enemies: Vec<Enemy>, hit_points: i32, cards: Vec<PlayerCard>, power: i32) -> Game {
+    let player = Player::new(hit_points, FpVec::from_vec(cards)).power_per_turn(power);
+    Game::start_encounter(FpVec::from_vec(enemies), player, 1)
+}
+
+fn chosen(strategy: &mut dyn PlayerStrategy, game: &Game) -> Vec<(String, Option<usize>)> {
+    strategy
+        .choose_cards(game)
+        .inner
+        .into_iter()
+        .map(|CardPlay { card, target, .. }| (card.name, target))
+        .collect()
+}
+
+#[test]
+fn greedy_skips_an_element_the_enemy_is_immune_to() {
+    let game = start(vec![dam()], 20, vec![splash(), gust()], 2);
+    assert_eq!(
+        chosen(&mut GreedyDamageStrategy, &game),
+        vec![("Gust".to_string(), Some(0))]
+    );
+}
+
+#[test]
+fn greedy_aims_at_the_enemy_it_hurts_most() {
+    let game = start(vec![windbreak(), dam()], 20, vec![gust(), splash()], 1);
+    // Only one card fits, so the bigger hit goes first.
+    assert_eq!(
+        chosen(&mut GreedyDamageStrategy, &game),
+        vec![("Splash".to_string(), Some(0))]
+    );
+    let game = start(vec![windbreak(), dam()], 20, vec![gust()], 1);
+    assert_eq!(
+        chosen(&mut GreedyDamageStrategy, &game),
+        vec![("Gust".to_string(), Some(1))]
+    );
+}
+
+#[test]
+fn conservative_heals_at_low_hit_points() {
+    let mut strategy = ConservativeHealStrategy::heal_below(5);
+
+    let game = start(vec![dummy()], 20, vec![gust(), mend()], 1);
+    assert_eq!(
+        chosen(&mut strategy, &game),
+        vec![("Gust".to_string(), Some(0))]
+    );
+    let game = start(vec![dummy()], 4, vec![gust(), mend()], 1);
+    assert_eq!(
+        chosen(&mut strategy, &game),
+        vec![("Mend".to_string(), Some(0))]
+    );
+}
+
+#[test]
+fn conservative_avoids_backlash_at_low_hit_points() {
+    let enemy = dummy().player_play_card_effect(OnCardPlayEffects::take_damage_on_play_elem(
+        ElementType::Water,
+        2,
+    ));
+    let game = start(vec![enemy.clone()], 4, vec![splash(), gust()], 2);
+    assert_eq!(
+        chosen(&mut ConservativeHealStrategy::heal_below(5), &game),
+        vec![("Gust".to_string(), Some(0))]
+    );
+    // With hit points to spare it takes the bigger hit and the backlash.
+    let game = start(vec![enemy], 20, vec![splash(), gust()], 2);
+    assert_eq!(
+        chosen(&mut ConservativeHealStrategy::heal_below(5), &game)[0].0,
+        "Splash"
+    );
+}
@@ -0,0 +1,43 @@
+use card_game_simulator::catalog::Catalog;
+use card_game_simulator::enemy::Enemy;
+use card_game_simulator::events::GameEvent;
+use card_game_simulator::fp_vec::FpVec;
+use card_game_simulator::game::Game;
+use card_game_simulator::player::{CardPlay, Player};
+use card_game_simulator::EffectTarget;
+
+fn solo(cards: &[&str], enemy: Enemy) -> Game {
+    let cards = Catalog::builtin().cards(cards).unwrap();
+    Game::start_seeded(enemy, Player::new(30, cards), 1)
+}
+
+fn play_all(game: Game) -> Game {
+    let cards = CardPlay::untargeted(game.player().hand.clone());
+    game.take_turn(cards)
+}
+
+#[test]
+fn enemy_shields_soften_card_damage() {
+    let game = play_all(solo(
+        &["stream"],
+        Catalog::builtin().enemy("sinkhole").unwrap(),
+    ));
+    assert_eq!(game.enemies.inner[0].hit_points, 14);
+}
+
+#[test]
+fn enemies_can_stun_players_and_halve_their_hit_points() {
+    let game = solo(&["stream"], Enemy::sinkhole()).take_turn(FpVec::new());
+    assert_eq!(game.player().hit_points, 29);
+
+    // Swallowed: the stream stays in hand and the sinkhole is untouched.
+    let game = play_all(game);
+    assert!(game.events.inner.contains(&GameEvent::TurnSkipped {
+        target: EffectTarget::PlayerAt(0),
+    }));
+    assert_eq!(game.player().hand.inner.len(), 1);
+    assert_eq!(game.enemies.inner[0].hit_points, 16);
+
+    // Collapse takes half of 29, then the usual attack lands.
+    assert_eq!(game.player().hit_points, 14);
+}
@@ -0,0 +1,82 @@
+use card_game_simulator::catalog::Catalog;
+use card_game_simulator::enemy::Enemy;
+use card_game_simulator::events::{BlockReason, GameEvent};
+use card_game_simulator::fp_vec::FpVec;
+use card_game_simulator::game::Game;
+use card_game_simulator::game_effects::{EnemyEffects, GameEffect};
+use card_game_simulator::player::{CardPlay, Player};
+use card_game_simulator::{Damage, EffectTrigger, EffectType};
+
+fn solo(cards: &[&str], enemy: Enemy) -> Game {
+    let cards = Catalog::builtin().cards(cards).unwrap();
+    Game::start_seeded(enemy, Player::new(30, cards), 1)
+}
+
+fn play_first(game: Game) -> Game {
+    let cards = FpVec::from_vec(game.player().hand.inner.iter().take(1).cloned().collect());
+    game.take_turn(CardPlay::untargeted(cards))
+}
+
+#[test]
+fn scorching_drought_drains_the_player_as_each_turn_starts() {
+    let catalog = Catalog::builtin();
+    assert_eq!(
+        catalog.enemy("scorching_drought").unwrap(),
+        Enemy::scorching_drought()
+    );
+
+    let game = solo(&[], Enemy::scorching_drought());
+    assert_eq!(game.player().hit_points, 29);
+    let game = game.take_turn(FpVec::new());
+    // Attacked for 2, then drained again as the next turn starts.
+    assert_eq!(game.player().hit_points, 26);
+}
+
+#[test]
+fn rising_floods_forbid_water_on_every_other_turn() {
+    assert_eq!(
+        Catalog::builtin().enemy("rising_floods").unwrap(),
+        Enemy::rising_floods()
+    );
+
+    let game = solo(&["riptide", "riptide", "riptide"], Enemy::rising_floods());
+    let game = play_first(game);
+    assert_eq!(game.enemies.inner[0].hit_points, 10);
+
+    let game = play_first(game);
+    assert_eq!(game.enemies.inner[0].hit_points, 10);
+    assert!(game.events.inner.iter().any(|event| matches!(
+        event,
+        GameEvent::CardBlocked {
+            reason: BlockReason::Forbidden,
+            ..
+        }
+    )));
+    // The water has receded by the time the turn is over.
+    assert!(game.player().statuses.inner.is_empty());
+
+    let game = play_first(game);
+    assert_eq!(game.enemies.inner[0].hit_points, 8);
+}
+
+#[test]
+fn enemies_act_after_the_cards_a_player_played() {
+    let undertow = GameEffect::player(
+        "Undertow",
+        EffectTrigger::Always(EffectType::Damage(Damage::raw(3))),
+    );
+    let enemy = Enemy::landslide()
+        .end_turn_effects_cleared()
+        .player_end_turn_effect(undertow)
+        .player_end_turn_effect(EnemyEffects::heal_self(4));
+    assert!(enemy
+        .description()
+        .contains("Player End Turn Effects [Undertow"));
+
+    let game = solo(&["stream"], enemy);
+    let cards = CardPlay::untargeted(game.player().hand.clone());
+    let game = game.take_player_turn(cards);
+    assert_eq!(game.player().hit_points, 27);
+    // Stream hit for 2 before the enemy healed.
+    assert_eq!(game.enemies.inner[0].hit_points, 22);
+}
@@ -0,0 +1,119 @@
+use card_game_simulator::catalog::Catalog;
+use card_game_simulator::condition::Situation;
+use card_game_simulator::enemy::Enemy;
+use card_game_simulator::events::{BlockReason, GameEvent};
+use card_game_simulator::fp_vec::FpVec;
+use card_game_simulator::game::Game;
+use card_game_simulator::game_effects::{CardEffects, OnCardPlayEffects};
+use card_game_simulator::player::{CardPlay, Player, PlayerCard};
+use card_game_simulator::{CardZone, DamageAdjustment, DefenseProps, EffectCondition, ElementType};
+
+fn dummy() -> Enemy {
+    let defense = DefenseProps {
+        wind: DamageAdjustment::Normal,
+        water: DamageAdjustment::Normal,
+        land: DamageAdjustment::Normal,
+        any: DamageAdjustment::Normal,
+    };
+    Enemy::new("Dummy", 20, defense, 1)
+}
+
+fn play(game: Game, names: &[&str]) -> Game {
+    let cards = names
+        .iter()
+        .filter_map(|name| {
+            game.player()
+                .hand
+                .inner
+                .iter()
+                .find(|card| card.name == *name)
+        })
+        .cloned()
+        .collect();
+    game.take_player_turn(CardPlay::untargeted(FpVec::from_vec(cards)))
+        .take_enemy_turn()
+}
+
+#[test]
+fn cards_are_played_once_and_only_from_hand() {
+    let cards = Catalog::builtin().cards(&["fire_hose"]).unwrap();
+    let game = Game::start(dummy(), Player::new(30, cards));
+    let hose = CardPlay::new(game.player().hand.inner[0].clone());
+    let stranger = CardPlay::new(
+        PlayerCard::new("Stranger", "", ElementType::Wind)
+            .play_card_effect(CardEffects::do_physical_damage(5)),
+    );
+    let plays = FpVec::from_vec(vec![hose.clone(), hose.clone(), stranger, hose]);
+    let game = game.take_player_turn(plays);
+
+    assert_eq!(game.enemies.inner[0].hit_points, 14);
+    assert!(game.player().hand.inner.is_empty());
+    assert_eq!(game.player().discard_pile.inner.len(), 1);
+    let blocked = game
+        .events
+        .inner
+        .iter()
+        .filter(|event| {
+            matches!(
+                event,
+                GameEvent::CardBlocked {
+                    reason: BlockReason::NotInHand,
+                    ..
+                }
+            )
+        })
+        .count();
+    assert_eq!(blocked, 3);
+}
+
+#[test]
+fn draws_reshuffle_the_discard_pile_once_the_library_runs_out() {
+    let cards = Catalog::builtin().cards(&["fire_hose", "gust"]).unwrap();
+    let player = Player::new(30, cards).starting_hand(1).draws_per_turn(1);
+    let game = Game::start(dummy(), player);
+    // The starting hand, then the first turn's draw.
+    assert_eq!(game.player().hand.inner.len(), 2);
+    assert!(game.player().library.inner.is_empty());
+
+    let game = play(game, &["Fire Hoses"]);
+    assert_eq!(game.player().hand.inner.len(), 2);
+    assert!(game.player().discard_pile.inner.is_empty());
+    assert!(game.player().library.inner.is_empty());
+
+    let game = play(game, &[]);
+    assert_eq!(game.player().hand.inner.len(), 2);
+}
+
+#[test]
+fn exiled_cards_leave_the_game() {
+    let flare = PlayerCard::new("Flare", "", ElementType::Wind)
+        .play_card_effect(CardEffects::do_physical_damage(2))
+        .play_card_effect(OnCardPlayEffects::exile_this_card());
+    let player = Player::new(30, FpVec::from_vec(vec![flare])).draws_per_turn(1);
+    let game = play(Game::start(dummy(), player), &["Flare"]);
+
+    assert_eq!(game.enemies.inner[0].hit_points, 18);
+    assert_eq!(game.player().exile.inner.len(), 1);
+    assert!(game.player().hand.inner.is_empty() && game.player().discard_pile.inner.is_empty());
+}
+
+#[test]
+fn element_conditions_look_at_their_zone() {
+    let cards = Catalog::builtin().cards(&["fire_hose", "gust"]).unwrap();
+    let game = Game::start(dummy(), Player::new(30, cards));
+    let has = |game: &Game, zone: CardZone| {
+        let situation = Situation::new(game.player(), &game.enemies.inner[0], 1);
+        EffectCondition::PlayerHasCardWithElement(zone, ElementType::Water).check(&situation)
+    };
+    assert!(has(&game, CardZone::Hand));
+    assert!(!has(&game, CardZone::Discard));
+
+    let game = play(game, &["Fire Hoses"]);
+    assert!(!has(&game, CardZone::Hand));
+    assert!(has(&game, CardZone::Discard));
+    let situation = Situation::new(game.player(), &game.enemies.inner[0], 2);
+    assert!(
+        EffectCondition::PlayerHasNoCardWithElement(CardZone::Hand, ElementType::Water)
+            .check(&situation)
+    );
+}